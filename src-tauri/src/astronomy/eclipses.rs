@@ -0,0 +1,257 @@
+// eclipses.rs
+// Solar and lunar eclipses (Meeus, chapter 54), and whether one can be seen from a given place
+
+use super::moon::{lunar_position, EARTH_RADIUS_KM, SYNODIC_MONTH};
+use super::sun::solar_position;
+use super::{altitude, delta_t, from_julian_day, julian_day, sidereal_time};
+use chrono::{DateTime, Datelike, Duration, Utc};
+
+// Mean angular semidiameter of the sun in degrees
+const SUN_SEMIDIAMETER: f64 = 0.2666;
+const MOON_RADIUS_KM: f64 = 1737.4;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EclipseKind {
+    Total,
+    Annular,
+    Hybrid,
+    Partial,
+    Penumbral,
+}
+
+impl EclipseKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            EclipseKind::Total => "Total",
+            EclipseKind::Annular => "Annular",
+            EclipseKind::Hybrid => "Hybrid",
+            EclipseKind::Partial => "Partial",
+            EclipseKind::Penumbral => "Penumbral",
+        }
+    }
+}
+
+pub struct Eclipse {
+    pub solar: bool,
+    pub kind: EclipseKind,
+    // Instant (UT) of greatest eclipse
+    pub greatest: DateTime<Utc>,
+    // Of the sun's diameter for a partial solar eclipse, of the moon's for a lunar one (umbral, or penumbral
+    // when the umbra is missed)
+    pub magnitude: f64,
+    // Half the time the moon is in the penumbra, for lunar eclipses
+    pub semiduration: Duration,
+}
+
+// The eclipse, if any, at the new (`solar`) or full moon of lunation `lunation`
+fn eclipse_at(lunation: i64, solar: bool) -> Option<Eclipse> {
+    let k = lunation as f64 + if solar { 0.0 } else { 0.5 };
+    let t = k / 1236.85;
+
+    let f = (160.7108 + 390.67050284 * k - 0.0016118 * t * t - 0.00000227 * t.powi(3)).to_radians();
+    // Far from a node, there is no eclipse
+    if f.sin().abs() > 0.36 {
+        return None;
+    }
+
+    let mean = 2451550.09766 + 29.530588861 * k + 0.00015437 * t * t - 0.000000150 * t.powi(3);
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+    let m = (2.5534 + 29.10535670 * k - 0.0000014 * t * t - 0.00000011 * t.powi(3)).to_radians();
+    let mp = (201.5643 + 385.81693528 * k + 0.0107582 * t * t + 0.00001238 * t.powi(3)).to_radians();
+    let omega = (124.7746 - 1.56375588 * k + 0.0020672 * t * t + 0.00000215 * t.powi(3)).to_radians();
+    let f1 = f - 0.02665_f64.to_radians() * omega.sin();
+    let a1 = (299.77 + 0.107408 * k - 0.009173 * t * t).to_radians();
+
+    let (c1, c2) = if solar { (-0.4075, 0.1721) } else { (-0.4065, 0.1727) };
+    let jde = mean + c1 * mp.sin() + c2 * e * m.sin() + 0.0161 * (2.0 * mp).sin() - 0.0097 * (2.0 * f1).sin()
+        + 0.0073 * e * (mp - m).sin()
+        - 0.0050 * e * (mp + m).sin()
+        - 0.0023 * (mp - 2.0 * f1).sin()
+        + 0.0021 * e * (2.0 * m).sin()
+        + 0.0012 * (mp + 2.0 * f1).sin()
+        + 0.0006 * e * (2.0 * mp + m).sin()
+        - 0.0004 * (3.0 * mp).sin()
+        - 0.0003 * e * (m + 2.0 * f1).sin()
+        + 0.0003 * a1.sin()
+        - 0.0002 * e * (m - 2.0 * f1).sin()
+        - 0.0002 * e * (2.0 * mp - m).sin()
+        - 0.0002 * omega.sin();
+
+    let p = 0.2070 * e * m.sin() + 0.0024 * e * (2.0 * m).sin() - 0.0392 * mp.sin() + 0.0116 * (2.0 * mp).sin()
+        - 0.0073 * e * (mp + m).sin()
+        + 0.0067 * e * (mp - m).sin()
+        + 0.0118 * (2.0 * f1).sin();
+    let q = 5.2207 - 0.0048 * e * m.cos() + 0.0020 * e * (2.0 * m).cos() - 0.3299 * mp.cos()
+        - 0.0060 * e * (mp + m).cos()
+        + 0.0041 * e * (mp - m).cos();
+    let w = f1.cos().abs();
+    // Least distance of the moon's shadow axis from the earth's centre, in earth radii
+    let gamma = (p * f1.cos() + q * f1.sin()) * (1.0 - 0.0048 * w);
+    let u = 0.0059 + 0.0046 * e * m.cos() - 0.0182 * mp.cos() + 0.0004 * (2.0 * mp).cos() - 0.0005 * (m + mp).cos();
+
+    let year = from_julian_day(jde).year() as f64;
+    let greatest = from_julian_day(jde - delta_t(year) / 86400.0);
+
+    if solar {
+        if gamma.abs() > 1.5433 + u {
+            return None;
+        }
+        let (kind, magnitude) = if gamma.abs() < 0.9972 {
+            let kind = if u < 0.0 {
+                EclipseKind::Total
+            } else if u < 0.00464 * (1.0 - gamma * gamma).sqrt() {
+                EclipseKind::Hybrid
+            } else {
+                EclipseKind::Annular
+            };
+            (kind, 1.0)
+        } else if gamma.abs() < 0.9972 + u.abs() {
+            // Non-central: the shadow axis misses the earth, but the edge of the umbra or antumbra touches it
+            (if u < 0.0 { EclipseKind::Total } else { EclipseKind::Annular }, 1.0)
+        } else {
+            (EclipseKind::Partial, (1.5433 + u - gamma.abs()) / (0.5461 + 2.0 * u))
+        };
+        return Some(Eclipse { solar, kind, greatest, magnitude, semiduration: Duration::zero() });
+    }
+
+    let penumbral = (1.5573 + u - gamma.abs()) / 0.5450;
+    let umbral = (1.0128 - u - gamma.abs()) / 0.5450;
+    if penumbral < 0.0 {
+        return None;
+    }
+    let kind = if umbral >= 1.0 {
+        EclipseKind::Total
+    } else if umbral > 0.0 {
+        EclipseKind::Partial
+    } else {
+        EclipseKind::Penumbral
+    };
+    let n = 0.5458 + 0.0400 * mp.cos();
+    let h = 1.5573 + u;
+    let minutes = 60.0 / n * (h * h - gamma * gamma).max(0.0).sqrt();
+    Some(Eclipse {
+        solar,
+        kind,
+        greatest,
+        magnitude: if umbral > 0.0 { umbral } else { penumbral },
+        semiduration: Duration::seconds((minutes * 60.0) as i64),
+    })
+}
+
+// The next solar (or lunar) eclipse whose greatest phase is after `time`
+pub fn next_eclipse(time: DateTime<Utc>, solar: bool) -> Eclipse {
+    let mut lunation = ((julian_day(time) - 2451550.09766) / SYNODIC_MONTH).floor() as i64;
+    loop {
+        if let Some(eclipse) = eclipse_at(lunation, solar).filter(|e| e.greatest > time) {
+            return eclipse;
+        }
+        lunation += 1;
+    }
+}
+
+// Topocentric angular separation of the centres of the sun and moon, with the moon's semidiameter
+fn topocentric_separation(jd: f64, latitude: f64, longitude: f64) -> (f64, f64) {
+    let sun = solar_position(jd);
+    let moon = lunar_position(jd);
+    // Parallax shifts the moon towards the horizon (Meeus, chapter 40, for a spherical earth)
+    let parallax = (EARTH_RADIUS_KM / moon.distance).asin();
+    let hour_angle = (sidereal_time(jd) + longitude - moon.right_ascension).to_radians();
+    let (phi, delta) = (latitude.to_radians(), moon.declination.to_radians());
+    let denominator = delta.cos() - phi.cos() * parallax.sin() * hour_angle.cos();
+    let shift = (-phi.cos() * parallax.sin() * hour_angle.sin()).atan2(denominator);
+    let ra = moon.right_ascension.to_radians() + shift;
+    let dec = ((delta.sin() - phi.sin() * parallax.sin()) * shift.cos()).atan2(denominator);
+
+    let (sun_ra, sun_dec) = (sun.right_ascension.to_radians(), sun.declination.to_radians());
+    let separation = (sun_dec.sin() * dec.sin() + sun_dec.cos() * dec.cos() * (sun_ra - ra).cos())
+        .clamp(-1.0, 1.0)
+        .acos()
+        .to_degrees();
+    (separation, (MOON_RADIUS_KM / moon.distance).asin().to_degrees())
+}
+
+// Where an eclipse can be seen from the place: for a solar eclipse, the instant of greatest local magnitude
+// (followed for three hours either side of greatest eclipse while the sun is up) and that magnitude; for a
+// lunar eclipse, the moment nearest greatest eclipse at which the moon is up during the penumbral phase
+pub fn local_circumstances(eclipse: &Eclipse, latitude: f64, longitude: f64) -> Option<(DateTime<Utc>, f64)> {
+    let span = if eclipse.solar { Duration::hours(3) } else { eclipse.semiduration };
+    let step = Duration::minutes(2);
+    let mut visible = Vec::new();
+    let mut time = eclipse.greatest - span;
+    while time <= eclipse.greatest + span {
+        let jd = julian_day(time);
+        if eclipse.solar {
+            let sun = solar_position(jd);
+            let sun_altitude = altitude(jd, latitude, longitude, sun.right_ascension, sun.declination);
+            let (separation, moon_semidiameter) = topocentric_separation(jd, latitude, longitude);
+            let magnitude = (SUN_SEMIDIAMETER + moon_semidiameter - separation) / (2.0 * SUN_SEMIDIAMETER);
+            if sun_altitude > -0.833 && magnitude > 0.0 {
+                visible.push((time, magnitude));
+            }
+        } else {
+            let moon = lunar_position(jd);
+            if altitude(jd, latitude, longitude, moon.right_ascension, moon.declination) > 0.0 {
+                visible.push((time, eclipse.magnitude));
+            }
+        }
+        time += step;
+    }
+
+    if eclipse.solar {
+        visible.into_iter().max_by(|a, b| a.1.total_cmp(&b.1))
+    } else {
+        visible.into_iter().min_by_key(|(time, _)| (*time - eclipse.greatest).num_seconds().abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn finds_the_2024_solar_eclipses() {
+        // Greatest eclipse 18:17 UT, then the annular eclipse of 2 October, greatest 18:45 UT
+        let total = next_eclipse(utc(2024, 3, 1, 0, 0), true);
+        assert_eq!(total.kind, EclipseKind::Total);
+        assert!((total.greatest - utc(2024, 4, 8, 18, 17)).num_seconds().abs() < 120, "{}", total.greatest);
+
+        let annular = next_eclipse(total.greatest, true);
+        assert_eq!(annular.kind, EclipseKind::Annular);
+        assert!((annular.greatest - utc(2024, 10, 2, 18, 45)).num_seconds().abs() < 120, "{}", annular.greatest);
+    }
+
+    #[test]
+    fn finds_the_2025_total_lunar_eclipse() {
+        // Greatest eclipse 06:59 UT, umbral magnitude 1.18, penumbral phases from 03:57 to 10:00 UT
+        let lunar = next_eclipse(utc(2025, 3, 1, 0, 0), false);
+        assert_eq!(lunar.kind, EclipseKind::Total);
+        assert!((lunar.greatest - utc(2025, 3, 14, 6, 59)).num_seconds().abs() < 120, "{}", lunar.greatest);
+        assert!((lunar.magnitude - 1.178).abs() < 0.01, "{}", lunar.magnitude);
+        assert!((lunar.semiduration - Duration::minutes(181)).num_minutes().abs() < 5, "{}", lunar.semiduration);
+    }
+
+    #[test]
+    fn sees_eclipses_only_where_they_happen() {
+        let solar = next_eclipse(utc(2024, 3, 1, 0, 0), true);
+        // Dallas lay on the path of totality, reached about 18:42 UT; New York saw nine-tenths; London nothing
+        let (time, magnitude) = local_circumstances(&solar, 32.78, -96.80).unwrap();
+        assert!(magnitude >= 1.0, "{}", magnitude);
+        assert!((time - utc(2024, 4, 8, 18, 42)).num_seconds().abs() < 300, "{}", time);
+        let (_, magnitude) = local_circumstances(&solar, 40.71, -74.01).unwrap();
+        assert!(magnitude > 0.85 && magnitude < 0.95, "{}", magnitude);
+        assert!(local_circumstances(&solar, 51.51, -0.13).is_none());
+
+        // The moon was up over the Americas and set in London mid-eclipse; Delhi was in daylight throughout
+        let lunar = next_eclipse(utc(2025, 3, 1, 0, 0), false);
+        let (time, _) = local_circumstances(&lunar, 32.78, -96.80).unwrap();
+        assert!((time - lunar.greatest).num_seconds().abs() < 120, "{}", time);
+        let (time, _) = local_circumstances(&lunar, 51.51, -0.13).unwrap();
+        assert!(time < lunar.greatest, "{}", time);
+        assert!(local_circumstances(&lunar, 28.61, 77.21).is_none());
+    }
+}
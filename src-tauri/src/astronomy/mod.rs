@@ -0,0 +1,117 @@
+// mod.rs
+// Shared astronomical helpers (Meeus, "Astronomical Algorithms")
+
+pub mod eclipses;
+pub mod moon;
+pub mod planets;
+pub mod seasons;
+pub mod sun;
+
+use chrono::{DateTime, Duration, Utc};
+
+// Julian Day of 1970-01-01T00:00:00Z
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+pub fn julian_day(time: DateTime<Utc>) -> f64 {
+    UNIX_EPOCH_JD + time.timestamp_millis() as f64 / 86_400_000.0
+}
+
+pub fn from_julian_day(jd: f64) -> DateTime<Utc> {
+    let millis = ((jd - UNIX_EPOCH_JD) * 86_400_000.0).round() as i64;
+    DateTime::<Utc>::from_timestamp_millis(millis).unwrap_or_default()
+}
+
+// ΔT = TT − UT in seconds, from the Espenak & Meeus polynomial fits
+pub fn delta_t(year: f64) -> f64 {
+    let y = year;
+    if (2005.0..2050.0).contains(&y) {
+        let t = y - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t * t
+    } else if (1986.0..2005.0).contains(&y) {
+        let t = y - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2) + 0.0017275 * t.powi(3) + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if (1961.0..1986.0).contains(&y) {
+        let t = y - 1975.0;
+        45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+    } else if (1941.0..1961.0).contains(&y) {
+        let t = y - 1950.0;
+        29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+    } else if (1920.0..1941.0).contains(&y) {
+        let t = y - 1920.0;
+        21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+    } else if (1900.0..1920.0).contains(&y) {
+        let t = y - 1900.0;
+        -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+    } else if (1860.0..1900.0).contains(&y) {
+        let t = y - 1860.0;
+        7.62 + 0.5737 * t - 0.251754 * t.powi(2) + 0.01680668 * t.powi(3) - 0.0004473624 * t.powi(4)
+            + t.powi(5) / 233174.0
+    } else if (1800.0..1860.0).contains(&y) {
+        let t = y - 1800.0;
+        13.72 - 0.332447 * t + 0.0068612 * t.powi(2) + 0.0041116 * t.powi(3) - 0.00037436 * t.powi(4)
+            + 0.0000121272 * t.powi(5) - 0.0000001699 * t.powi(6) + 0.000000000875 * t.powi(7)
+    } else if (1700.0..1800.0).contains(&y) {
+        let t = y - 1700.0;
+        8.83 + 0.1603 * t - 0.0059285 * t.powi(2) + 0.00013336 * t.powi(3) - t.powi(4) / 1174000.0
+    } else if (1600.0..1700.0).contains(&y) {
+        let t = y - 1600.0;
+        120.0 - 0.9808 * t - 0.01532 * t.powi(2) + t.powi(3) / 7129.0
+    } else if (2050.0..2150.0).contains(&y) {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - y)
+    } else {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    }
+}
+
+// Greenwich mean sidereal time in degrees (Meeus 12.4)
+pub fn sidereal_time(jd: f64) -> f64 {
+    let t = (jd - 2451545.0) / 36525.0;
+    (280.46061837 + 360.98564736629 * (jd - 2451545.0) + 0.000387933 * t * t - t.powi(3) / 38710000.0)
+        .rem_euclid(360.0)
+}
+
+// Geometric altitude (degrees) of a body at the given right ascension and declination
+pub fn altitude(jd: f64, latitude: f64, longitude: f64, right_ascension: f64, declination: f64) -> f64 {
+    let hour_angle = (sidereal_time(jd) + longitude - right_ascension).to_radians();
+    let (phi, delta) = (latitude.to_radians(), declination.to_radians());
+    (phi.sin() * delta.sin() + phi.cos() * delta.cos() * hour_angle.cos()).asin().to_degrees()
+}
+
+// First rising and first setting between `start` and `end`, where `height` is a body's altitude above
+// its rising altitude; sampled every ten minutes, then halved down to a few seconds
+pub fn horizon_crossings(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    height: impl Fn(DateTime<Utc>) -> f64,
+) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+    const STEP_MINUTES: i64 = 10;
+    let (mut rise, mut set) = (None, None);
+    let mut previous = (start, height(start));
+    while previous.0 < end && (rise.is_none() || set.is_none()) {
+        let time = (previous.0 + Duration::minutes(STEP_MINUTES)).min(end);
+        let current = (time, height(time));
+        if (previous.1 < 0.0) != (current.1 < 0.0) {
+            let (mut low, mut high) = (previous.0, current.0);
+            let rising = previous.1 < 0.0;
+            while high - low > Duration::seconds(5) {
+                let middle = low + (high - low) / 2;
+                if (height(middle) < 0.0) == rising {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            let crossing = low + (high - low) / 2;
+            if rising {
+                rise = rise.or(Some(crossing));
+            } else {
+                set = set.or(Some(crossing));
+            }
+        }
+        previous = current;
+    }
+    (rise, set)
+}
@@ -0,0 +1,398 @@
+// moon.rs
+// Position of the moon (Meeus, chapter 47), its illuminated fraction (chapter 48) and the
+// instants of its principal phases (chapter 49)
+
+use super::sun::solar_position;
+use super::{altitude, delta_t, from_julian_day, horizon_crossings, julian_day};
+use chrono::{DateTime, Datelike, Utc};
+
+// Mean length of the synodic month in days
+pub const SYNODIC_MONTH: f64 = 29.530588861;
+
+// Mean distance of the sun in kilometres, near enough for the phase angle
+const SUN_DISTANCE_KM: f64 = 149_597_870.7;
+pub const EARTH_RADIUS_KM: f64 = 6378.14;
+
+// Periodic terms for longitude and distance: multiples of D, M, M', F, then Σl (10⁻⁶ degree) and Σr (metres)
+const LONGITUDE_TERMS: [(f64, f64, f64, f64, f64, f64); 32] = [
+    (0.0, 0.0, 1.0, 0.0, 6288774.0, -20905355.0),
+    (2.0, 0.0, -1.0, 0.0, 1274027.0, -3699111.0),
+    (2.0, 0.0, 0.0, 0.0, 658314.0, -2955968.0),
+    (0.0, 0.0, 2.0, 0.0, 213618.0, -569925.0),
+    (0.0, 1.0, 0.0, 0.0, -185116.0, 48888.0),
+    (0.0, 0.0, 0.0, 2.0, -114332.0, -3149.0),
+    (2.0, 0.0, -2.0, 0.0, 58793.0, 246158.0),
+    (2.0, -1.0, -1.0, 0.0, 57066.0, -152138.0),
+    (2.0, 0.0, 1.0, 0.0, 53322.0, -170733.0),
+    (2.0, -1.0, 0.0, 0.0, 45758.0, -204586.0),
+    (0.0, 1.0, -1.0, 0.0, -40923.0, -129620.0),
+    (1.0, 0.0, 0.0, 0.0, -34720.0, 108743.0),
+    (0.0, 1.0, 1.0, 0.0, -30383.0, 104755.0),
+    (2.0, 0.0, 0.0, -2.0, 15327.0, 10321.0),
+    (0.0, 0.0, 1.0, 2.0, -12528.0, 0.0),
+    (0.0, 0.0, 1.0, -2.0, 10980.0, 79661.0),
+    (4.0, 0.0, -1.0, 0.0, 10675.0, -34782.0),
+    (0.0, 0.0, 3.0, 0.0, 10034.0, -23210.0),
+    (4.0, 0.0, -2.0, 0.0, 8548.0, -21636.0),
+    (2.0, 1.0, -1.0, 0.0, -7888.0, 24208.0),
+    (2.0, 1.0, 0.0, 0.0, -6766.0, 30824.0),
+    (1.0, 0.0, -1.0, 0.0, -5163.0, -8379.0),
+    (1.0, 1.0, 0.0, 0.0, 4987.0, -16675.0),
+    (2.0, -1.0, 1.0, 0.0, 4036.0, -12831.0),
+    (2.0, 0.0, 2.0, 0.0, 3994.0, -10445.0),
+    (4.0, 0.0, 0.0, 0.0, 3861.0, -11650.0),
+    (2.0, 0.0, -3.0, 0.0, 3665.0, 14403.0),
+    (0.0, 1.0, -2.0, 0.0, -2689.0, -7003.0),
+    (2.0, 0.0, -1.0, 2.0, -2602.0, 0.0),
+    (2.0, -1.0, -2.0, 0.0, 2390.0, 10056.0),
+    (1.0, 0.0, 1.0, 0.0, -2348.0, 6322.0),
+    (2.0, -2.0, 0.0, 0.0, 2236.0, -9884.0),
+];
+
+// Periodic terms for latitude: multiples of D, M, M', F, then Σb (10⁻⁶ degree)
+const LATITUDE_TERMS: [(f64, f64, f64, f64, f64); 15] = [
+    (0.0, 0.0, 0.0, 1.0, 5128122.0),
+    (0.0, 0.0, 1.0, 1.0, 280602.0),
+    (0.0, 0.0, 1.0, -1.0, 277693.0),
+    (2.0, 0.0, 0.0, -1.0, 173237.0),
+    (2.0, 0.0, -1.0, 1.0, 55413.0),
+    (2.0, 0.0, -1.0, -1.0, 46271.0),
+    (2.0, 0.0, 0.0, 1.0, 32573.0),
+    (0.0, 0.0, 2.0, 1.0, 17198.0),
+    (2.0, 0.0, 1.0, -1.0, 9266.0),
+    (0.0, 0.0, 2.0, -1.0, 8822.0),
+    (2.0, -1.0, 0.0, -1.0, 8216.0),
+    (2.0, 0.0, -2.0, -1.0, 4324.0),
+    (2.0, 0.0, 1.0, 1.0, 4200.0),
+    (2.0, 1.0, 0.0, -1.0, -3359.0),
+    (2.0, -1.0, -1.0, 1.0, 2463.0),
+];
+
+pub struct LunarPosition {
+    // Degrees
+    pub longitude: f64,
+    pub latitude: f64,
+    pub right_ascension: f64,
+    pub declination: f64,
+    // Kilometres from the centre of the earth
+    pub distance: f64,
+}
+
+fn polynomial(t: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * t + c)
+}
+
+// Geocentric position at Julian Day `jd` (UT), to within a few minutes of arc
+pub fn lunar_position(jd: f64) -> LunarPosition {
+    let jde = jd + delta_t(2000.0 + (jd - 2451545.0) / 365.25) / 86400.0;
+    let t = (jde - 2451545.0) / 36525.0;
+
+    let mean_longitude = polynomial(t, &[218.3164477, 481267.88123421, -0.0015786, 1.0 / 538841.0, -1.0 / 65194000.0]);
+    let elongation = polynomial(t, &[297.8501921, 445267.1114034, -0.0018819, 1.0 / 545868.0, -1.0 / 113065000.0]);
+    let sun_anomaly = polynomial(t, &[357.5291092, 35999.0502909, -0.0001536, 1.0 / 24490000.0]);
+    let moon_anomaly = polynomial(t, &[134.9633964, 477198.8675055, 0.0087414, 1.0 / 69699.0, -1.0 / 14712000.0]);
+    let node_distance = polynomial(t, &[93.2720950, 483202.0175233, -0.0036539, -1.0 / 3526000.0, 1.0 / 863310000.0]);
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+
+    let argument = |d: f64, m: f64, mp: f64, f: f64| {
+        (d * elongation + m * sun_anomaly + mp * moon_anomaly + f * node_distance).to_radians()
+    };
+    // Terms in the sun's anomaly shrink with the eccentricity of the earth's orbit
+    let eccentricity = |m: f64| e.powi(m.abs() as i32);
+
+    let a1 = (119.75 + 131.849 * t).to_radians();
+    let a2 = (53.09 + 479264.290 * t).to_radians();
+    let a3 = (313.45 + 481266.484 * t).to_radians();
+    let l = mean_longitude.to_radians();
+    let f = node_distance.to_radians();
+    let mp = moon_anomaly.to_radians();
+
+    let mut sum_l = 3958.0 * a1.sin() + 1962.0 * (l - f).sin() + 318.0 * a2.sin();
+    let mut sum_r = 0.0;
+    for &(d, m, mpm, fm, cl, cr) in LONGITUDE_TERMS.iter() {
+        let arg = argument(d, m, mpm, fm);
+        sum_l += cl * eccentricity(m) * arg.sin();
+        sum_r += cr * eccentricity(m) * arg.cos();
+    }
+    let mut sum_b = -2235.0 * l.sin() + 382.0 * a3.sin() + 175.0 * (a1 - f).sin() + 175.0 * (a1 + f).sin()
+        + 127.0 * (l - mp).sin() - 115.0 * (l + mp).sin();
+    for &(d, m, mpm, fm, cb) in LATITUDE_TERMS.iter() {
+        sum_b += cb * eccentricity(m) * argument(d, m, mpm, fm).sin();
+    }
+
+    let longitude = (mean_longitude + sum_l / 1_000_000.0).rem_euclid(360.0);
+    let latitude = sum_b / 1_000_000.0;
+    let distance = 385000.56 + sum_r / 1000.0;
+
+    let obliquity = (23.4392911 - 0.0130042 * t).to_radians();
+    let (lambda, beta) = (longitude.to_radians(), latitude.to_radians());
+    let right_ascension = (lambda.sin() * obliquity.cos() - beta.tan() * obliquity.sin())
+        .atan2(lambda.cos())
+        .to_degrees()
+        .rem_euclid(360.0);
+    let declination = (beta.sin() * obliquity.cos() + beta.cos() * obliquity.sin() * lambda.sin())
+        .asin()
+        .to_degrees();
+
+    LunarPosition { longitude, latitude, right_ascension, declination, distance }
+}
+
+// Moon's longitude less the sun's, 0 at new moon and 180 at full
+pub fn elongation(jd: f64) -> f64 {
+    (lunar_position(jd).longitude - solar_position(jd).longitude).rem_euclid(360.0)
+}
+
+// Fraction of the disc lit by the sun, 0 to 1
+pub fn illuminated_fraction(jd: f64) -> f64 {
+    let moon = lunar_position(jd);
+    let sun = solar_position(jd);
+    let psi = (moon.latitude.to_radians().cos() * (moon.longitude - sun.longitude).to_radians().cos()).acos();
+    let phase_angle = (SUN_DISTANCE_KM * psi.sin()).atan2(moon.distance - SUN_DISTANCE_KM * psi.cos());
+    (1.0 + phase_angle.cos()) / 2.0
+}
+
+/* --- Principal phases --- */
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Phase {
+    New,
+    FirstQuarter,
+    Full,
+    LastQuarter,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 4] = [Phase::New, Phase::FirstQuarter, Phase::Full, Phase::LastQuarter];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::New => "New Moon",
+            Phase::FirstQuarter => "First Quarter",
+            Phase::Full => "Full Moon",
+            Phase::LastQuarter => "Last Quarter",
+        }
+    }
+
+    fn offset(self) -> f64 {
+        match self {
+            Phase::New => 0.0,
+            Phase::FirstQuarter => 0.25,
+            Phase::Full => 0.5,
+            Phase::LastQuarter => 0.75,
+        }
+    }
+}
+
+// Corrections for new and full moon: coefficient, power of E, then multiples of M, M', F and Ω
+const NEW_MOON_TERMS: [(f64, i32, f64, f64, f64, f64); 25] = [
+    (-0.40720, 0, 0.0, 1.0, 0.0, 0.0),
+    (0.17241, 1, 1.0, 0.0, 0.0, 0.0),
+    (0.01608, 0, 0.0, 2.0, 0.0, 0.0),
+    (0.01039, 0, 0.0, 0.0, 2.0, 0.0),
+    (0.00739, 1, -1.0, 1.0, 0.0, 0.0),
+    (-0.00514, 1, 1.0, 1.0, 0.0, 0.0),
+    (0.00208, 2, 2.0, 0.0, 0.0, 0.0),
+    (-0.00111, 0, 0.0, 1.0, -2.0, 0.0),
+    (-0.00057, 0, 0.0, 1.0, 2.0, 0.0),
+    (0.00056, 1, 1.0, 2.0, 0.0, 0.0),
+    (-0.00042, 0, 0.0, 3.0, 0.0, 0.0),
+    (0.00042, 1, 1.0, 0.0, 2.0, 0.0),
+    (0.00038, 1, 1.0, 0.0, -2.0, 0.0),
+    (-0.00024, 1, -1.0, 2.0, 0.0, 0.0),
+    (-0.00017, 0, 0.0, 0.0, 0.0, 1.0),
+    (-0.00007, 0, 2.0, 1.0, 0.0, 0.0),
+    (0.00004, 0, 0.0, 2.0, -2.0, 0.0),
+    (0.00004, 0, 3.0, 0.0, 0.0, 0.0),
+    (0.00003, 0, 1.0, 1.0, -2.0, 0.0),
+    (0.00003, 0, 0.0, 2.0, 2.0, 0.0),
+    (-0.00003, 0, 1.0, 1.0, 2.0, 0.0),
+    (0.00003, 0, -1.0, 1.0, 2.0, 0.0),
+    (-0.00002, 0, -1.0, 1.0, -2.0, 0.0),
+    (-0.00002, 0, 1.0, 3.0, 0.0, 0.0),
+    (0.00002, 0, 0.0, 4.0, 0.0, 0.0),
+];
+
+// Full moon differs from new moon only in the first seven coefficients
+const FULL_MOON_COEFFICIENTS: [f64; 7] = [-0.40614, 0.17302, 0.01614, 0.01043, 0.00734, -0.00515, 0.00209];
+
+const QUARTER_TERMS: [(f64, i32, f64, f64, f64, f64); 25] = [
+    (-0.62801, 0, 0.0, 1.0, 0.0, 0.0),
+    (0.17172, 1, 1.0, 0.0, 0.0, 0.0),
+    (-0.01183, 1, 1.0, 1.0, 0.0, 0.0),
+    (0.00862, 0, 0.0, 2.0, 0.0, 0.0),
+    (0.00804, 0, 0.0, 0.0, 2.0, 0.0),
+    (0.00454, 1, -1.0, 1.0, 0.0, 0.0),
+    (0.00204, 2, 2.0, 0.0, 0.0, 0.0),
+    (-0.00180, 0, 0.0, 1.0, -2.0, 0.0),
+    (-0.00070, 0, 0.0, 1.0, 2.0, 0.0),
+    (-0.00040, 0, 0.0, 3.0, 0.0, 0.0),
+    (-0.00034, 1, -1.0, 2.0, 0.0, 0.0),
+    (0.00032, 1, 1.0, 0.0, 2.0, 0.0),
+    (0.00032, 1, 1.0, 0.0, -2.0, 0.0),
+    (-0.00028, 2, 2.0, 1.0, 0.0, 0.0),
+    (0.00027, 1, 1.0, 2.0, 0.0, 0.0),
+    (-0.00017, 0, 0.0, 0.0, 0.0, 1.0),
+    (-0.00005, 0, -1.0, 1.0, -2.0, 0.0),
+    (0.00004, 0, 0.0, 2.0, 2.0, 0.0),
+    (-0.00004, 0, 1.0, 1.0, 2.0, 0.0),
+    (0.00004, 0, -2.0, 1.0, 0.0, 0.0),
+    (0.00003, 0, 1.0, 1.0, -2.0, 0.0),
+    (0.00003, 0, 3.0, 0.0, 0.0, 0.0),
+    (0.00002, 0, 0.0, 2.0, -2.0, 0.0),
+    (0.00002, 0, -1.0, 1.0, 2.0, 0.0),
+    (-0.00002, 0, 1.0, 3.0, 0.0, 0.0),
+];
+
+// Planetary arguments (A1 to A14): constant, rate per lunation, and coefficient
+const PLANETARY_TERMS: [(f64, f64, f64); 14] = [
+    (299.77, 0.107408, 0.000325),
+    (251.88, 0.016321, 0.000165),
+    (251.83, 26.651886, 0.000164),
+    (349.42, 36.412478, 0.000126),
+    (84.66, 18.206239, 0.000110),
+    (141.74, 53.303771, 0.000062),
+    (207.14, 2.453732, 0.000060),
+    (154.84, 7.306860, 0.000056),
+    (34.52, 27.261239, 0.000047),
+    (207.19, 0.121824, 0.000042),
+    (291.34, 1.844379, 0.000040),
+    (161.72, 24.198154, 0.000037),
+    (239.56, 25.513099, 0.000035),
+    (331.55, 3.592518, 0.000023),
+];
+
+// Instant (UT) of `phase` in lunation `lunation`, counted from the new moon of 6 January 2000
+fn phase_instant(lunation: i64, phase: Phase) -> DateTime<Utc> {
+    let k = lunation as f64 + phase.offset();
+    let t = k / 1236.85;
+
+    let mean = polynomial(t, &[2451550.09766, 0.0, 0.00015437, -0.000000150, 0.00000000073]) + SYNODIC_MONTH * k;
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+    let m = (2.5534 + 29.10535670 * k - 0.0000014 * t * t - 0.00000011 * t.powi(3)).to_radians();
+    let mp = (201.5643 + 385.81693528 * k + polynomial(t, &[0.0, 0.0, 0.0107582, 0.00001238, -0.000000058]))
+        .to_radians();
+    let f = (160.7108 + 390.67050284 * k + polynomial(t, &[0.0, 0.0, -0.0016118, -0.00000227, 0.000000011]))
+        .to_radians();
+    let omega = (124.7746 - 1.56375588 * k + 0.0020672 * t * t + 0.00000215 * t.powi(3)).to_radians();
+
+    let term = |(c, e_power, mm, mpm, fm, om): (f64, i32, f64, f64, f64, f64)| {
+        c * e.powi(e_power) * (mm * m + mpm * mp + fm * f + om * omega).sin()
+    };
+    let mut correction: f64 = match phase {
+        Phase::New => NEW_MOON_TERMS.iter().copied().map(term).sum(),
+        Phase::Full => NEW_MOON_TERMS
+            .iter()
+            .enumerate()
+            .map(|(i, &(c, e_power, mm, mpm, fm, om))| {
+                term((FULL_MOON_COEFFICIENTS.get(i).copied().unwrap_or(c), e_power, mm, mpm, fm, om))
+            })
+            .sum(),
+        Phase::FirstQuarter | Phase::LastQuarter => QUARTER_TERMS.iter().copied().map(term).sum(),
+    };
+
+    if matches!(phase, Phase::FirstQuarter | Phase::LastQuarter) {
+        let w = 0.00306 - 0.00038 * e * m.cos() + 0.00026 * mp.cos() - 0.00002 * (mp - m).cos()
+            + 0.00002 * (mp + m).cos()
+            + 0.00002 * (2.0 * f).cos();
+        correction += if phase == Phase::FirstQuarter { w } else { -w };
+    }
+
+    correction += PLANETARY_TERMS
+        .iter()
+        .enumerate()
+        .map(|(i, &(a, rate, c))| {
+            let mut argument = a + rate * k;
+            if i == 0 {
+                argument -= 0.009173 * t * t;
+            }
+            c * argument.to_radians().sin()
+        })
+        .sum::<f64>();
+
+    let jde = mean + correction;
+    let year = from_julian_day(jde).year() as f64;
+    from_julian_day(jde - delta_t(year) / 86400.0)
+}
+
+fn lunation_near(time: DateTime<Utc>) -> i64 {
+    ((julian_day(time) - 2451550.09766) / SYNODIC_MONTH).floor() as i64
+}
+
+// The first instant of `phase` strictly after `time`
+pub fn next_phase(time: DateTime<Utc>, phase: Phase) -> DateTime<Utc> {
+    let mut lunation = lunation_near(time) - 1;
+    loop {
+        let instant = phase_instant(lunation, phase);
+        if instant > time {
+            return instant;
+        }
+        lunation += 1;
+    }
+}
+
+// The last instant of `phase` at or before `time`
+pub fn previous_phase(time: DateTime<Utc>, phase: Phase) -> DateTime<Utc> {
+    let mut lunation = lunation_near(time) + 1;
+    loop {
+        let instant = phase_instant(lunation, phase);
+        if instant <= time {
+            return instant;
+        }
+        lunation -= 1;
+    }
+}
+
+/* --- Rising and setting --- */
+
+// Altitude of the moon's centre above the rising altitude h0 = 0.7275π − 0°34′, which allows for
+// parallax and refraction; positive while the upper limb is above the horizon
+fn altitude_above_horizon(time: DateTime<Utc>, latitude: f64, longitude: f64) -> f64 {
+    let jd = julian_day(time);
+    let moon = lunar_position(jd);
+    let parallax = (EARTH_RADIUS_KM / moon.distance).asin().to_degrees();
+    altitude(jd, latitude, longitude, moon.right_ascension, moon.declination) - (0.7275 * parallax - 34.0 / 60.0)
+}
+
+// Moonrise and moonset between `start` and `end`, either of which may not happen
+pub fn rise_and_set(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    latitude: f64,
+    longitude: f64,
+) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+    horizon_crossings(start, end, |time| altitude_above_horizon(time, latitude, longitude))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn finds_known_phases() {
+        // USNO phase times, to the minute
+        for (after, phase, expected) in [
+            ((2024, 1, 1), Phase::LastQuarter, (2024, 1, 4, 3, 30)),
+            ((2024, 1, 1), Phase::New, (2024, 1, 11, 11, 57)),
+            ((2024, 1, 1), Phase::FirstQuarter, (2024, 1, 18, 3, 53)),
+            ((2024, 1, 1), Phase::Full, (2024, 1, 25, 17, 54)),
+            ((2024, 4, 1), Phase::New, (2024, 4, 8, 18, 21)),
+            ((2024, 9, 1), Phase::Full, (2024, 9, 18, 2, 34)),
+        ] {
+            let (y, m, d, h, min) = expected;
+            let expected = Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap();
+            let start = Utc.with_ymd_and_hms(after.0, after.1, after.2, 0, 0, 0).unwrap();
+            let found = next_phase(start, phase);
+            assert!((found - expected).num_seconds().abs() < 120, "{} {}: {}", phase.name(), expected, found);
+            assert_eq!(previous_phase(found, phase), found, "{} {}", phase.name(), expected);
+        }
+    }
+
+    #[test]
+    fn lights_the_full_moon() {
+        let full = Utc.with_ymd_and_hms(2024, 9, 18, 2, 34, 0).unwrap();
+        let new = Utc.with_ymd_and_hms(2024, 4, 8, 18, 21, 0).unwrap();
+        assert!(illuminated_fraction(julian_day(full)) > 0.99);
+        assert!(illuminated_fraction(julian_day(new)) < 0.01);
+    }
+}
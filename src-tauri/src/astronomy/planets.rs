@@ -0,0 +1,195 @@
+// planets.rs
+// Geocentric positions of the naked-eye planets from mean Keplerian elements (Standish, "Approximate
+// Positions of the Planets", JPL, valid 1800–2050), good to a few minutes of arc
+
+use super::{altitude, delta_t, horizon_crossings, julian_day};
+use chrono::{DateTime, Utc};
+
+// Altitude of a star-like body's centre at rise and set, for refraction
+const PLANET_RISING_ALTITUDE: f64 = -0.5667;
+
+// Semi-major axis (AU), eccentricity, inclination, mean longitude, longitude of perihelion and of the
+// ascending node (degrees) at J2000, each followed by its rate per Julian century
+struct Elements {
+    name: &'static str,
+    a: (f64, f64),
+    e: (f64, f64),
+    i: (f64, f64),
+    l: (f64, f64),
+    perihelion: (f64, f64),
+    node: (f64, f64),
+}
+
+const EARTH: Elements = Elements {
+    name: "Earth",
+    a: (1.00000261, 0.00000562),
+    e: (0.01671123, -0.00004392),
+    i: (-0.00001531, -0.01294668),
+    l: (100.46457166, 35999.37244981),
+    perihelion: (102.93768193, 0.32327364),
+    node: (0.0, 0.0),
+};
+
+const PLANETS: [Elements; 5] = [
+    Elements {
+        name: "Mercury",
+        a: (0.38709927, 0.00000037),
+        e: (0.20563593, 0.00001906),
+        i: (7.00497902, -0.00594749),
+        l: (252.25032350, 149472.67411175),
+        perihelion: (77.45779628, 0.16047689),
+        node: (48.33076593, -0.12534081),
+    },
+    Elements {
+        name: "Venus",
+        a: (0.72333566, 0.00000390),
+        e: (0.00677672, -0.00004107),
+        i: (3.39467605, -0.00078890),
+        l: (181.97909950, 58517.81538729),
+        perihelion: (131.60246718, 0.00268329),
+        node: (76.67984255, -0.27769418),
+    },
+    Elements {
+        name: "Mars",
+        a: (1.52371034, 0.00001847),
+        e: (0.09339410, 0.00007882),
+        i: (1.84969142, -0.00813131),
+        l: (-4.55343205, 19140.30268499),
+        perihelion: (-23.94362959, 0.44441088),
+        node: (49.55953891, -0.29257343),
+    },
+    Elements {
+        name: "Jupiter",
+        a: (5.20288700, -0.00011607),
+        e: (0.04838624, -0.00013253),
+        i: (1.30439695, -0.00183714),
+        l: (34.39644051, 3034.74612775),
+        perihelion: (14.72847983, 0.21252668),
+        node: (100.47390909, 0.20469106),
+    },
+    Elements {
+        name: "Saturn",
+        a: (9.53667594, -0.00125060),
+        e: (0.05386179, -0.00050991),
+        i: (2.48599187, 0.00193609),
+        l: (49.95424423, 1222.49362201),
+        perihelion: (92.59887831, -0.41897216),
+        node: (113.66242448, -0.28867794),
+    },
+];
+
+pub const PLANET_COUNT: usize = PLANETS.len();
+
+pub struct PlanetPosition {
+    pub name: &'static str,
+    // Degrees, referred to the equinox of date
+    pub right_ascension: f64,
+    pub declination: f64,
+}
+
+// Heliocentric ecliptic coordinates (AU, J2000 ecliptic) at `t` Julian centuries from J2000
+fn heliocentric(elements: &Elements, t: f64) -> (f64, f64, f64) {
+    let at = |(value, rate): (f64, f64)| value + rate * t;
+    let (a, e) = (at(elements.a), at(elements.e));
+    let (i, node) = (at(elements.i).to_radians(), at(elements.node));
+    let perihelion = at(elements.perihelion);
+    let argument = (perihelion - node).to_radians();
+    let node = node.to_radians();
+    let mean_anomaly = ((at(elements.l) - perihelion + 180.0).rem_euclid(360.0) - 180.0).to_radians();
+
+    // Kepler's equation by Newton's method
+    let mut eccentric = mean_anomaly + e * mean_anomaly.sin();
+    for _ in 0..6 {
+        eccentric -= (eccentric - e * eccentric.sin() - mean_anomaly) / (1.0 - e * eccentric.cos());
+    }
+    let x = a * (eccentric.cos() - e);
+    let y = a * (1.0 - e * e).sqrt() * eccentric.sin();
+
+    let (cw, sw, cn, sn, ci, si) = (argument.cos(), argument.sin(), node.cos(), node.sin(), i.cos(), i.sin());
+    (
+        (cw * cn - sw * sn * ci) * x + (-sw * cn - cw * sn * ci) * y,
+        (cw * sn + sw * cn * ci) * x + (-sw * sn + cw * cn * ci) * y,
+        (sw * si) * x + (cw * si) * y,
+    )
+}
+
+pub fn planet_position(index: usize, jd: f64) -> PlanetPosition {
+    let jde = jd + delta_t(2000.0 + (jd - 2451545.0) / 365.25) / 86400.0;
+    let t = (jde - 2451545.0) / 36525.0;
+    let planet = &PLANETS[index];
+    let (px, py, pz) = heliocentric(planet, t);
+    let (ex, ey, ez) = heliocentric(&EARTH, t);
+    let (x, y, z) = (px - ex, py - ey, pz - ez);
+
+    // Ecliptic longitude carried forward from J2000 by general precession, then to the equator of date
+    let longitude = y.atan2(x) + (1.396971 * t).to_radians();
+    let latitude = z.atan2((x * x + y * y).sqrt());
+    let obliquity = (23.4392911 - 0.0130042 * t).to_radians();
+    let right_ascension = (longitude.sin() * obliquity.cos() - latitude.tan() * obliquity.sin())
+        .atan2(longitude.cos())
+        .to_degrees()
+        .rem_euclid(360.0);
+    let declination = (latitude.sin() * obliquity.cos() + latitude.cos() * obliquity.sin() * longitude.sin())
+        .asin()
+        .to_degrees();
+
+    PlanetPosition { name: planet.name, right_ascension, declination }
+}
+
+pub fn planet_altitude(index: usize, time: DateTime<Utc>, latitude: f64, longitude: f64) -> f64 {
+    let jd = julian_day(time);
+    let position = planet_position(index, jd);
+    altitude(jd, latitude, longitude, position.right_ascension, position.declination)
+}
+
+// Rising and setting of the planet between `start` and `end`, either of which may not happen
+pub fn planet_rise_and_set(
+    index: usize,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    latitude: f64,
+    longitude: f64,
+) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+    horizon_crossings(start, end, |time| planet_altitude(index, time, latitude, longitude) - PLANET_RISING_ALTITUDE)
+}
+
+// Angular distance in degrees between two points given by right ascension and declination
+pub fn separation(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> f64 {
+    let (d1, d2) = (dec1.to_radians(), dec2.to_radians());
+    (d1.sin() * d2.sin() + d1.cos() * d2.cos() * (ra1 - ra2).to_radians().cos())
+        .clamp(-1.0, 1.0)
+        .acos()
+        .to_degrees()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn position(name: &str, jd: f64) -> PlanetPosition {
+        let index = PLANETS.iter().position(|p| p.name == name).unwrap();
+        planet_position(index, jd)
+    }
+
+    #[test]
+    fn places_the_great_conjunction_of_2020() {
+        // Jupiter passed 0.1° from Saturn on 21 December 2020, near right ascension 20h 10m, declination −20.6°
+        let jd = julian_day(Utc.with_ymd_and_hms(2020, 12, 21, 18, 0, 0).unwrap());
+        let (jupiter, saturn) = (position("Jupiter", jd), position("Saturn", jd));
+        let distance = separation(jupiter.right_ascension, jupiter.declination, saturn.right_ascension, saturn.declination);
+        assert!(distance < 0.2, "{}", distance);
+        assert!((jupiter.right_ascension - 302.5).abs() < 0.5, "{}", jupiter.right_ascension);
+        assert!((jupiter.declination + 20.6).abs() < 0.5, "{}", jupiter.declination);
+    }
+
+    #[test]
+    fn puts_mars_opposite_the_sun_at_opposition() {
+        // Mars reached opposition on 13 October 2020, when the sun stood near right ascension 13h 10m
+        let jd = julian_day(Utc.with_ymd_and_hms(2020, 10, 13, 23, 0, 0).unwrap());
+        let mars = position("Mars", jd);
+        let sun = crate::astronomy::sun::solar_position(jd);
+        let distance = separation(mars.right_ascension, mars.declination, sun.right_ascension, sun.declination);
+        assert!(distance > 170.0, "{}", distance);
+    }
+}
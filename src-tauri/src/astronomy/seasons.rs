@@ -0,0 +1,159 @@
+// seasons.rs
+// Instants of the equinoxes and solstices (Meeus, chapter 27), and of any longitude of the sun
+
+use super::sun::solar_position;
+use super::{delta_t, from_julian_day, julian_day};
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Cardinal {
+    MarchEquinox,
+    JuneSolstice,
+    SeptemberEquinox,
+    DecemberSolstice,
+}
+
+impl Cardinal {
+    pub const ALL: [Cardinal; 4] =
+        [Cardinal::MarchEquinox, Cardinal::JuneSolstice, Cardinal::SeptemberEquinox, Cardinal::DecemberSolstice];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Cardinal::MarchEquinox => "March equinox",
+            Cardinal::JuneSolstice => "June solstice",
+            Cardinal::SeptemberEquinox => "September equinox",
+            Cardinal::DecemberSolstice => "December solstice",
+        }
+    }
+
+    // Apparent longitude of the sun, in degrees
+    pub fn longitude(self) -> f64 {
+        match self {
+            Cardinal::MarchEquinox => 0.0,
+            Cardinal::JuneSolstice => 90.0,
+            Cardinal::SeptemberEquinox => 180.0,
+            Cardinal::DecemberSolstice => 270.0,
+        }
+    }
+
+    // Coefficients of the mean instant for years 1000 to 3000, then for −1000 to 1000
+    fn coefficients(self) -> ([f64; 5], [f64; 5]) {
+        match self {
+            Cardinal::MarchEquinox => (
+                [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+                [1721139.29189, 365242.13740, 0.06134, 0.00111, -0.00071],
+            ),
+            Cardinal::JuneSolstice => (
+                [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+                [1721233.25401, 365241.72562, -0.05323, 0.00907, 0.00025],
+            ),
+            Cardinal::SeptemberEquinox => (
+                [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+                [1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074],
+            ),
+            Cardinal::DecemberSolstice => (
+                [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032],
+                [1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006],
+            ),
+        }
+    }
+}
+
+// Periodic terms (A, B, C) correcting the mean instant
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+// Mean instant (JDE) of the equinox or solstice
+fn mean_instant(year: i32, cardinal: Cardinal) -> f64 {
+    let (modern, ancient) = cardinal.coefficients();
+    let (y, c) = if year >= 1000 {
+        ((year as f64 - 2000.0) / 1000.0, modern)
+    } else {
+        (year as f64 / 1000.0, ancient)
+    };
+
+    c[0] + c[1] * y + c[2] * y.powi(2) + c[3] * y.powi(3) + c[4] * y.powi(4)
+}
+
+// Instant (UT) of the equinox or solstice in the given Gregorian year
+pub fn season_instant(year: i32, cardinal: Cardinal) -> DateTime<Utc> {
+    let jde0 = mean_instant(year, cardinal);
+    let t = (jde0 - 2451545.0) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+
+    let jde = jde0 + 0.00001 * s / delta_lambda;
+    from_julian_day(jde - delta_t(year as f64) / 86400.0)
+}
+
+pub fn september_equinox(year: i32) -> DateTime<Utc> {
+    season_instant(year, Cardinal::SeptemberEquinox)
+}
+
+// The instant nearest `near` at which the sun's apparent longitude is `longitude` degrees, found by
+// stepping at the sun's mean rate until within a few seconds
+pub fn solar_longitude_instant(longitude: f64, near: DateTime<Utc>) -> DateTime<Utc> {
+    const DAYS_PER_DEGREE: f64 = 365.2422 / 360.0;
+    let mut jd = julian_day(near);
+    for _ in 0..10 {
+        let difference = (longitude - solar_position(jd).longitude + 180.0).rem_euclid(360.0) - 180.0;
+        jd += difference * DAYS_PER_DEGREE;
+        if difference.abs() < 0.00005 {
+            break;
+        }
+    }
+    from_julian_day(jd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn finds_the_2024_equinoxes_and_solstices() {
+        // USNO times, to the minute
+        for (cardinal, (m, d, h, min)) in Cardinal::ALL.into_iter().zip([
+            (3, 20, 3, 6),
+            (6, 20, 20, 51),
+            (9, 22, 12, 44),
+            (12, 21, 9, 20),
+        ]) {
+            let expected = Utc.with_ymd_and_hms(2024, m, d, h, min, 0).unwrap();
+            let found = season_instant(2024, cardinal);
+            assert!((found - expected).num_seconds().abs() < 120, "{}: {}", cardinal.name(), found);
+
+            // The low-precision solar position is good to about 0.01°, a quarter of an hour of the sun's motion
+            let stepped = solar_longitude_instant(cardinal.longitude(), found);
+            assert!((stepped - expected).num_seconds().abs() < 900, "{}: {}", cardinal.name(), stepped);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// sun.rs
+// Sun position and the times it crosses a given altitude (NOAA solar calculator, after Meeus)
+
+use super::julian_day;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+// Altitude of the sun's centre at rise and set: refraction at the horizon plus the semidiameter
+const SUNRISE_ALTITUDE: f64 = -0.833;
+
+pub struct SolarPosition {
+    // Degrees
+    pub longitude: f64,
+    pub right_ascension: f64,
+    pub declination: f64,
+    // Minutes by which apparent solar time runs ahead of mean solar time
+    pub equation_of_time: f64,
+}
+
+pub fn solar_position(jd: f64) -> SolarPosition {
+    let t = (jd - 2451545.0) / 36525.0;
+
+    let mean_longitude = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let mean_anomaly = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+
+    let m = mean_anomaly.to_radians();
+    let center = m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * m).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * m).sin() * 0.000289;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_longitude = (mean_longitude + center - 0.00569 - 0.00478 * omega.sin()).to_radians();
+
+    let mean_obliquity = 23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliquity = (mean_obliquity + 0.00256 * omega.cos()).to_radians();
+    let declination = (obliquity.sin() * apparent_longitude.sin()).asin().to_degrees();
+    let right_ascension = (obliquity.cos() * apparent_longitude.sin())
+        .atan2(apparent_longitude.cos())
+        .to_degrees()
+        .rem_euclid(360.0);
+
+    let y = (obliquity / 2.0).tan().powi(2);
+    let l = mean_longitude.to_radians();
+    let equation_of_time = 4.0
+        * (y * (2.0 * l).sin() - 2.0 * eccentricity * m.sin()
+            + 4.0 * eccentricity * y * m.sin() * (2.0 * l).cos()
+            - 0.5 * y * y * (4.0 * l).sin()
+            - 1.25 * eccentricity * eccentricity * (2.0 * m).sin())
+        .to_degrees();
+
+    SolarPosition {
+        longitude: apparent_longitude.to_degrees().rem_euclid(360.0),
+        right_ascension,
+        declination,
+        equation_of_time,
+    }
+}
+
+// Altitude of sunrise and sunset for an observer `elevation` metres above the surrounding terrain,
+// who sees past the geometric horizon
+pub fn horizon_altitude(elevation: f64) -> f64 {
+    SUNRISE_ALTITUDE - 2.076 * elevation.max(0.0).sqrt() / 60.0
+}
+
+fn at_minutes(date: NaiveDate, minutes: f64) -> DateTime<Utc> {
+    date.and_time(chrono::NaiveTime::MIN).and_utc() + Duration::milliseconds((minutes * 60_000.0).round() as i64)
+}
+
+// Hour angle (degrees) at which the sun stands at `altitude`; None if it stays above or below all day
+fn hour_angle(latitude: f64, declination: f64, altitude: f64) -> Option<f64> {
+    let (lat, dec) = (latitude.to_radians(), declination.to_radians());
+    let cos_h = (altitude.to_radians().sin() - lat.sin() * dec.sin()) / (lat.cos() * dec.cos());
+    (-1.0..=1.0).contains(&cos_h).then(|| cos_h.acos().to_degrees())
+}
+
+// Local apparent noon on the civil date at `longitude` (degrees east)
+pub fn solar_noon(date: NaiveDate, longitude: f64) -> DateTime<Utc> {
+    let mut minutes = 720.0 - 4.0 * longitude;
+    for _ in 0..2 {
+        let position = solar_position(julian_day(at_minutes(date, minutes)));
+        minutes = 720.0 - 4.0 * longitude - position.equation_of_time;
+    }
+    at_minutes(date, minutes)
+}
+
+// When the sun's centre passes `altitude` degrees on the civil date, rising if `morning` and setting
+// otherwise; refined by recomputing the sun's position at the previous estimate
+pub fn time_at_altitude(date: NaiveDate, latitude: f64, longitude: f64, altitude: f64, morning: bool) -> Option<DateTime<Utc>> {
+    let mut minutes = 720.0 - 4.0 * longitude;
+    for _ in 0..3 {
+        let position = solar_position(julian_day(at_minutes(date, minutes)));
+        let h = hour_angle(latitude, position.declination, altitude)?;
+        let noon = 720.0 - 4.0 * longitude - position.equation_of_time;
+        minutes = if morning { noon - 4.0 * h } else { noon + 4.0 * h };
+    }
+    Some(at_minutes(date, minutes))
+}
+
+// Altitude of the sun's centre at local apparent noon, ignoring refraction
+pub fn noon_altitude(date: NaiveDate, latitude: f64, longitude: f64) -> f64 {
+    let declination = solar_position(julian_day(solar_noon(date, longitude))).declination;
+    90.0 - (latitude - declination).abs()
+}
@@ -0,0 +1,152 @@
+// anniversaries.rs
+// Hebrew birthdays, bar/bat mitzvah dates and yahrzeits for the people listed in settings.json
+
+use super::jewish::{
+    days_in_hebrew_month, format_hebrew_date, gregorian_from_hebrew, hebrew_from_gregorian, is_jewish_leap_year,
+    last_adar, HebrewDate, ADAR, ADAR_II, CHESHVAN, KISLEV, SHEVAT, TEVET,
+};
+use crate::models::{HebrewAnniversary, Person, UserSettings};
+use chrono::{Duration, NaiveDate};
+
+// How far ahead the lock screen looks for anniversaries
+const UPCOMING_DAYS: i64 = 7;
+
+// Hebrew date of an event; after sunset the Hebrew day has already turned
+fn hebrew_event_date(date: Option<&String>, after_sunset: Option<bool>) -> Option<HebrewDate> {
+    let date = NaiveDate::parse_from_str(date?.trim(), "%Y-%m-%d").ok()?;
+    let date = if after_sunset.unwrap_or(false) { date + Duration::days(1) } else { date };
+    Some(hebrew_from_gregorian(date))
+}
+
+// Someone born in the last Adar of the year keeps the last Adar (Adar II in a leap year); a 30th that
+// the year lacks rolls over to the first of the next month
+fn hebrew_birthday(birth: HebrewDate, year: i32) -> NaiveDate {
+    let month = if birth.month == last_adar(birth.year) { last_adar(year) } else { birth.month };
+    gregorian_from_hebrew(HebrewDate { year, month, day: birth.day })
+}
+
+// Following Dershowitz & Reingold: a death on a 30th that the year after the death lacks is kept on the
+// 29th, and one on 30 Adar I falls back to 30 Shevat in a common year. A death in Adar of a common
+// year is kept in a leap year according to `adar_custom` ("adar_i", "adar_ii" or "both"), except for
+// the first yahrzeit, which completes the twelve months in Adar I.
+fn yahrzeits(death: HebrewDate, year: i32, adar_custom: &str) -> Vec<NaiveDate> {
+    let on = |month: u32, day: u32| gregorian_from_hebrew(HebrewDate { year, month, day });
+
+    if death.month == CHESHVAN && death.day == 30 && days_in_hebrew_month(death.year + 1, CHESHVAN) == 29 {
+        vec![on(KISLEV, 1) - Duration::days(1)]
+    } else if death.month == KISLEV && death.day == 30 && days_in_hebrew_month(death.year + 1, KISLEV) == 29 {
+        vec![on(TEVET, 1) - Duration::days(1)]
+    } else if death.month == ADAR_II {
+        vec![on(last_adar(year), death.day)]
+    } else if death.month == ADAR && death.day == 30 && !is_jewish_leap_year(year) {
+        vec![on(SHEVAT, 30)]
+    } else if death.month == ADAR && !is_jewish_leap_year(death.year) && is_jewish_leap_year(year) {
+        match adar_custom {
+            _ if year == death.year + 1 => vec![on(ADAR, death.day)],
+            "adar_ii" => vec![on(ADAR_II, death.day)],
+            "both" => vec![on(ADAR, death.day), on(ADAR_II, death.day)],
+            _ => vec![on(ADAR, death.day)],
+        }
+    } else {
+        vec![on(death.month, death.day)]
+    }
+}
+
+fn anniversary(settings: &UserSettings, person: &Person, kind: &str, date: NaiveDate, years: i32) -> HebrewAnniversary {
+    HebrewAnniversary {
+        name: person.name.clone(),
+        kind: kind.to_string(),
+        date,
+        hebrew_date: format_hebrew_date(hebrew_from_gregorian(date), Some(settings)),
+        years,
+    }
+}
+
+// Every anniversary that falls in the given Hebrew year
+fn anniversaries_in_year(settings: &UserSettings, year: i32) -> Vec<HebrewAnniversary> {
+    let adar_custom = settings.jewish_yahrzeit_adar.as_deref().unwrap_or("adar_i");
+    let mut found = Vec::new();
+
+    for person in settings.people.iter().flatten() {
+        if let Some(birth) = hebrew_event_date(person.birth_date.as_ref(), person.born_after_sunset) {
+            // Bar mitzvah at thirteen, bat mitzvah at twelve, in place of that year's birthday
+            let age = year - birth.year;
+            let kind = match (person.gender.as_deref(), age) {
+                (Some("male"), 13) => "bar_mitzvah",
+                (Some("female"), 12) => "bat_mitzvah",
+                _ => "birthday",
+            };
+            if age > 0 {
+                found.push(anniversary(settings, person, kind, hebrew_birthday(birth, year), age));
+            }
+        }
+
+        if let Some(death) = hebrew_event_date(person.death_date.as_ref(), person.died_after_sunset) {
+            let years = year - death.year;
+            if years > 0 {
+                for date in yahrzeits(death, year, adar_custom) {
+                    found.push(anniversary(settings, person, "yahrzeit", date, years));
+                }
+            }
+        }
+    }
+    found
+}
+
+// The next `count` anniversaries on or after `from`, soonest first
+pub fn upcoming_anniversaries(settings: &UserSettings, from: NaiveDate, count: usize) -> Vec<HebrewAnniversary> {
+    // Without a single date there is nothing to find, however many years we look through
+    let dated = settings.people.iter().flatten().any(|person| {
+        hebrew_event_date(person.birth_date.as_ref(), person.born_after_sunset).is_some()
+            || hebrew_event_date(person.death_date.as_ref(), person.died_after_sunset).is_some()
+    });
+    if !dated {
+        return Vec::new();
+    }
+
+    let first_year = hebrew_from_gregorian(from).year;
+    let Some(last_year) = i32::try_from(count).ok().and_then(|count| first_year.checked_add(count)) else {
+        return Vec::new();
+    };
+    let mut found = Vec::new();
+
+    // Everyone with a date has at least one anniversary a year, so `count` years always suffice
+    for year in first_year..=last_year {
+        found.extend(anniversaries_in_year(settings, year).into_iter().filter(|a| a.date >= from));
+        if found.len() >= count {
+            break;
+        }
+    }
+    found.sort_by_key(|a| a.date);
+    found.truncate(count);
+    found
+}
+
+// Lock screen summary of the anniversaries in the coming week: "Yahrzeit of Sarah (5) tomorrow"
+pub fn anniversary_notes(settings: &UserSettings, today: NaiveDate) -> Vec<String> {
+    let until = today + Duration::days(UPCOMING_DAYS);
+    let year = hebrew_from_gregorian(today).year;
+    let mut found: Vec<HebrewAnniversary> = (year..=year + 1)
+        .flat_map(|y| anniversaries_in_year(settings, y))
+        .filter(|a| a.date >= today && a.date < until)
+        .collect();
+    found.sort_by_key(|a| a.date);
+
+    found
+        .into_iter()
+        .map(|a| {
+            let what = match a.kind.as_str() {
+                "birthday" => format!("{}'s Hebrew birthday ({})", a.name, a.years),
+                "bar_mitzvah" => format!("{}'s bar mitzvah", a.name),
+                "bat_mitzvah" => format!("{}'s bat mitzvah", a.name),
+                _ => format!("Yahrzeit of {} ({})", a.name, a.years),
+            };
+            let when = match (a.date - today).num_days() {
+                0 => "today".to_string(),
+                1 => "tomorrow".to_string(),
+                n => format!("in {} days", n),
+            };
+            format!("{} {}", what, when)
+        })
+        .collect()
+}
@@ -1,6 +1,7 @@
 // buddhist.rs
-// Adds 543 to the standard year
+// Adds 543 to the standard year; Uposatha days come from the Thai lunar calendar
 
+use super::thai_lunar::uposatha_notes;
 use crate::models::CalendarDate;
 use chrono::{DateTime, Local, Datelike};
 
@@ -12,7 +13,7 @@ impl super::Calendar for BuddhistCalendar {
         CalendarDate {
             system: "Buddhist".to_string(),
             date: format!("{}, {} BE", date.format("%B %d"), buddhist_year),
-            additional_info: None,
+            additional_info: Some(uposatha_notes(date.date_naive()).join(" · ")),
         }
     }
 }
\ No newline at end of file
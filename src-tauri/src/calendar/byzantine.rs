@@ -0,0 +1,50 @@
+// byzantine.rs
+// Julian months with the Anno Mundi year (creation in 5509 BC) beginning on 1 September, plus the indiction
+
+use super::julian::julian_from_absolute;
+use super::month_name;
+use crate::models::CalendarDate;
+use chrono::{DateTime, Datelike, Local};
+
+pub struct ByzantineCalendar;
+
+// Genitive forms, as written in dates ("5 Οκτωβρίου")
+const GREEK_MONTH_NAMES: [&str; 12] = [
+    "Ιανουαρίου", "Φεβρουαρίου", "Μαρτίου", "Απριλίου", "Μαΐου", "Ιουνίου",
+    "Ιουλίου", "Αυγούστου", "Σεπτεμβρίου", "Οκτωβρίου", "Νοεμβρίου", "Δεκεμβρίου",
+];
+
+// 1 September 5509 BC (Julian) opens AM 1, so AD years map to AM + 5508 before September and + 5509 from it
+const ANNO_MUNDI_OFFSET: i32 = 5508;
+
+impl super::Calendar for ByzantineCalendar {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let abs = date.naive_local().date().num_days_from_ce() as i64;
+        let (year, month, day) = julian_from_absolute(abs);
+
+        let anno_mundi = if month >= 9 {
+            year + ANNO_MUNDI_OFFSET + 1
+        } else {
+            year + ANNO_MUNDI_OFFSET
+        };
+
+        // The indiction cycle also turns over on 1 September; a remainder of 0 is the 15th indiction
+        let indiction = match anno_mundi.rem_euclid(15) {
+            0 => 15,
+            n => n,
+        };
+
+        let greek_months = settings.and_then(|s| s.byzantine_greek_months).unwrap_or(false);
+        let month_str = if greek_months {
+            GREEK_MONTH_NAMES[(month - 1) as usize]
+        } else {
+            month_name(month)
+        };
+
+        CalendarDate {
+            system: "Byzantine".to_string(),
+            date: format!("{} {} {} AM", day, month_str, anno_mundi),
+            additional_info: Some(format!("Indiction {}", indiction)),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// chronology.rs
+// The traditional chronological cycles of the year: golden number, epacts, dominical letters, solar cycle,
+// indiction, Julian Period and the Hebrew 19-year cycle
+
+use super::jewish::{hebrew_from_gregorian, is_jewish_leap_year};
+use super::julian::{absolute_from_julian, is_julian_leap_year, julian_from_absolute};
+use crate::models::CalendarDate;
+use crate::utils::roman_numeral;
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+
+pub struct ChronologyCalendar;
+
+const LETTERS: [char; 7] = ['A', 'B', 'C', 'D', 'E', 'F', 'G'];
+
+// Year of the 19-year lunar cycle, 1 to 19
+fn golden_number(year: i32) -> i32 {
+    year.rem_euclid(19) + 1
+}
+
+// Age of the ecclesiastical moon on 1 January, with the Gregorian solar and lunar corrections
+fn gregorian_epact(year: i32) -> i32 {
+    let golden = golden_number(year);
+    let century = year.div_euclid(100) + 1;
+    let solar = 3 * century / 4 - 12;
+    let lunar = (8 * century + 5) / 25 - 5;
+    (11 * golden + 20 + lunar - solar).rem_euclid(30)
+}
+
+// The unreformed epact of the Julian calendar, viii in the year of golden number 1
+fn julian_epact(year: i32) -> i32 {
+    (11 * (golden_number(year) - 1) + 8).rem_euclid(30)
+}
+
+// Lower-case Roman numerals, as in the tables of the missal; an epact of 0 (or 30) is written *, and 25 in a
+// year of golden number above 11 is set in Arabic figures, as the missal distinguishes it from xxv
+fn epact_numeral(epact: i32, golden: i32) -> String {
+    if epact == 0 {
+        "*".to_string()
+    } else if epact == 25 && golden > 11 {
+        "25".to_string()
+    } else {
+        roman_numeral(epact as u32).to_lowercase()
+    }
+}
+
+// The letter of 1 January is A, so the Sunday letter follows from the weekday it falls on (Rata Die, 0 = Sunday);
+// a leap year takes a second letter, one earlier, from March
+fn dominical_letters(new_year_abs: i64, leap: bool) -> String {
+    let first = (7 - new_year_abs.rem_euclid(7)).rem_euclid(7) as usize;
+    if leap {
+        format!("{}{}", LETTERS[first], LETTERS[(first + 6) % 7])
+    } else {
+        LETTERS[first].to_string()
+    }
+}
+
+// Year of the 28-year cycle after which weekdays repeat on the Julian calendar
+fn solar_cycle(year: i32) -> i32 {
+    match (year + 9).rem_euclid(28) {
+        0 => 28,
+        n => n,
+    }
+}
+
+// The Roman indiction, reckoned from 1 January (the Byzantine calendar turns it over in September)
+fn indiction(year: i32) -> i32 {
+    match (year + 3).rem_euclid(15) {
+        0 => 15,
+        n => n,
+    }
+}
+
+// Scaliger's period of 7980 years, the product of the solar cycle, golden number and indiction, from 4713 BC
+fn julian_period(year: i32) -> i32 {
+    year + 4713
+}
+
+impl super::Calendar for ChronologyCalendar {
+    fn convert(&self, date: &DateTime<Local>, _settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let gregorian = date.naive_local().date();
+        let year = gregorian.year();
+        let (julian_year, _, _) = julian_from_absolute(gregorian.num_days_from_ce() as i64);
+
+        let gregorian_new_year = NaiveDate::from_ymd_opt(year, 1, 1).unwrap().num_days_from_ce() as i64;
+        let gregorian_letters = dominical_letters(gregorian_new_year, gregorian.leap_year());
+        let julian_letters = dominical_letters(
+            absolute_from_julian(julian_year, 1, 1),
+            is_julian_leap_year(julian_year),
+        );
+
+        let hebrew_year = hebrew_from_gregorian(gregorian).year;
+        let metonic_year = (hebrew_year - 1).rem_euclid(19) + 1;
+        let metonic_cycle = (hebrew_year - 1).div_euclid(19) + 1;
+
+        let info = [
+            format!("Solar cycle {}", solar_cycle(year)),
+            format!("Indiction {}", indiction(year)),
+            format!("Julian Period {}", julian_period(year)),
+            format!(
+                "Julian {}: epact {}, letter{} {}",
+                julian_year,
+                epact_numeral(julian_epact(julian_year), golden_number(julian_year)),
+                if julian_letters.len() > 1 { "s" } else { "" },
+                julian_letters
+            ),
+            format!(
+                "Hebrew {}: year {} of cycle {} ({})",
+                hebrew_year,
+                metonic_year,
+                metonic_cycle,
+                if is_jewish_leap_year(hebrew_year) { "leap" } else { "common" }
+            ),
+        ];
+
+        CalendarDate {
+            system: "Chronological Cycles".to_string(),
+            date: format!(
+                "Golden Number {} · Epact {} · Dominical Letter{} {}",
+                roman_numeral(golden_number(year) as u32),
+                epact_numeral(gregorian_epact(year), golden_number(year)),
+                if gregorian_letters.len() > 1 { "s" } else { "" },
+                gregorian_letters
+            ),
+            additional_info: Some(info.join(" · ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_gregorian_epacts() {
+        for (year, epact) in [(2000, "xxiv"), (2019, "xxiv"), (2025, "*"), (2026, "xi"), (2030, "25"), (2038, "xxiv")] {
+            assert_eq!(epact_numeral(gregorian_epact(year), golden_number(year)), epact, "{}", year);
+        }
+    }
+}
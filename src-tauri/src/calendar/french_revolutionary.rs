@@ -1,22 +1,74 @@
 // french_revolution.rs
 // Uses French Revolutionary date and displays item associated with date
 
+use crate::astronomy::seasons::september_equinox;
 use crate::models::CalendarDate;
-use chrono::{DateTime, Local, NaiveDate, Datelike};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate};
 
 pub struct FrenchRevolutionaryCalendar;
 
+// 1 Vendémiaire An I
+const FRENCH_EPOCH: (i32, u32, u32) = (1792, 9, 22);
+
+// Paris Observatory (2°20′14″ E) keeps local mean time 9 min 21 s ahead of UT
+const PARIS_OFFSET_SECONDS: i64 = 561;
+
+const DECADE_DAYS: [&str; 10] = [
+    "Primidi", "Duodi", "Tridi", "Quartidi", "Quintidi", "Sextidi", "Septidi", "Octidi", "Nonidi", "Décadi",
+];
+
+const SANSCULOTTIDES: [&str; 6] = [
+    "Jour de la Vertu",
+    "Jour du Génie",
+    "Jour du Travail",
+    "Jour de l'Opinion",
+    "Jour des Récompenses",
+    "Jour de la Révolution",
+];
+
+// 1 Vendémiaire falls on the day of the autumnal equinox at the Paris Observatory
+fn equinox_new_year(gregorian_year: i32) -> NaiveDate {
+    let equinox = september_equinox(gregorian_year);
+    (equinox + Duration::seconds(PARIS_OFFSET_SECONDS)).date_naive()
+}
+
+fn epoch() -> NaiveDate {
+    let (y, m, d) = FRENCH_EPOCH;
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+// Romme's rule: a sextile year every 4 years, except centuries not divisible by 400, and every 4000 years
+fn romme_new_year(year: i32) -> NaiveDate {
+    let y = (year - 1) as i64;
+    epoch() + Duration::days(365 * y + y / 4 - y / 100 + y / 400 - y / 4000)
+}
+
+// (first day of the year, year number) for the year containing `naive`
+fn locate_year(naive: NaiveDate, romme: bool) -> (NaiveDate, i32) {
+    if romme {
+        let approx = ((naive - epoch()).num_days() + 2) as f64 * 4000.0 / 1460969.0;
+        let approx = approx.floor() as i32 + 1;
+        let year = if naive < romme_new_year(approx) { approx - 1 } else { approx };
+        (romme_new_year(year), year)
+    } else {
+        let gregorian_year = naive.year();
+        let this_start = equinox_new_year(gregorian_year);
+        if naive >= this_start {
+            (this_start, gregorian_year - 1791)
+        } else {
+            (equinox_new_year(gregorian_year - 1), gregorian_year - 1792)
+        }
+    }
+}
+
 impl super::Calendar for FrenchRevolutionaryCalendar {
-    fn convert(&self, date: &DateTime<Local>, _settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate {
         let naive = date.naive_local().date();
-        let year = naive.year();
+        let romme = settings
+            .and_then(|s| s.french_revolutionary_leap_rule.as_deref())
+            .is_some_and(|rule| rule == "romme");
 
-        let vend_start_this = NaiveDate::from_ymd_opt(year, 9, 22).unwrap();
-        let (start_of_year, revolutionary_year) = if naive >= vend_start_this {
-            (vend_start_this, year - 1792 + 1)
-        } else {
-            (NaiveDate::from_ymd_opt(year - 1, 9, 22).unwrap(), year - 1792)
-        };
+        let (start_of_year, revolutionary_year) = locate_year(naive, romme);
 
         let days_since_start = (naive - start_of_year).num_days();
         let month_index = (days_since_start / 30) as usize;
@@ -27,469 +79,500 @@ impl super::Calendar for FrenchRevolutionaryCalendar {
             "Germinal", "Floréal", "Prairial", "Messidor", "Thermidor", "Fructidor",
         ];
 
-        let month_name = if month_index < 12 {
-            month_names[month_index].to_string()
+        let item = get_french_revolutionary_item(month_index, day_in_month);
+        let item_str = format!(
+            "{} ({}), {} — {}",
+            item.name,
+            item.english,
+            item.category.label(),
+            item.description
+        );
+
+        // The complementary days are named rather than numbered and sit outside the décades;
+        // the sixth only exists in sextile years
+        let (date_str, info) = if month_index < 12 {
+            let decade = (day_in_month - 1) / 10 + 1;
+            (
+                format!(
+                    "{}, {} {}, An {}",
+                    DECADE_DAYS[(day_in_month - 1) % 10],
+                    month_names[month_index],
+                    day_in_month,
+                    revolutionary_year
+                ),
+                format!("Décade {} · {}", month_index * 3 + decade, item_str),
+            )
         } else {
-            "Sansculottides".to_string()
+            (
+                format!("{}, An {}", SANSCULOTTIDES[day_in_month - 1], revolutionary_year),
+                item_str,
+            )
         };
 
-        let date_str = format!("{} {}, An {}", month_name, day_in_month, revolutionary_year);
-        let item = get_french_revolutionary_item(month_index, day_in_month);
-
         CalendarDate {
             system: "French Revolutionary".to_string(),
             date: date_str,
-            additional_info: Some(item),
+            additional_info: Some(info),
         }
     }
 }
 
-fn get_french_revolutionary_item(month: usize, day: usize) -> String {
-    match month {
-        0 => {
-            // Vendémiaire
-            let items = [
-                "Raisin",
-                "Safran",
-                "Châtaigne",
-                "Colchique",
-                "Cheval",
-                "Balsamine",
-                "Carotte",
-                "Amaranthe",
-                "Panais",
-                "Cuve",
-                "Pomme de terre",
-                "Immortelle",
-                "Potiron",
-                "Réséda",
-                "Âne",
-                "Belle de nuit",
-                "Citrouille",
-                "Sarrasin",
-                "Tournesol",
-                "Pressoir",
-                "Chanvre",
-                "Pêche",
-                "Navet",
-                "Amaryllis",
-                "Bœuf",
-                "Aubergine",
-                "Piment",
-                "Tomate",
-                "Orge",
-                "Tonneau",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
+#[derive(Clone, Copy, PartialEq)]
+enum ItemCategory {
+    Plant,
+    Animal,
+    Mineral,
+    Tool,
+    Festival,
+}
+
+impl ItemCategory {
+    fn label(self) -> &'static str {
+        match self {
+            ItemCategory::Plant => "plant",
+            ItemCategory::Animal => "animal",
+            ItemCategory::Mineral => "mineral",
+            ItemCategory::Tool => "tool",
+            ItemCategory::Festival => "festival",
         }
-        1 => {
+    }
+}
+
+struct FrenchRevolutionaryItem {
+    name: &'static str,
+    english: &'static str,
+    description: &'static str,
+    category: ItemCategory,
+}
+
+// Days ending in 5 honour an animal and days ending in 0 a tool; the rest of Nivôse is given to
+// minerals and the rest of the year to plants
+fn item_category(month: usize, day: usize) -> ItemCategory {
+    match (month, day % 10) {
+        (12, _) => ItemCategory::Festival,
+        (_, 5) => ItemCategory::Animal,
+        (_, 0) => ItemCategory::Tool,
+        (3, _) => ItemCategory::Mineral,
+        _ => ItemCategory::Plant,
+    }
+}
+
+// (French name, English translation, short description)
+fn get_french_revolutionary_item(month: usize, day: usize) -> FrenchRevolutionaryItem {
+    let items: &[(&str, &str, &str)] = match month {
+        0 => &[
+            // Vendémiaire
+            ("Raisin", "Grape", "fruit of the vine, harvested this month"),
+            ("Safran", "Saffron", "crocus whose stigmas give a golden spice"),
+            ("Châtaigne", "Chestnut", "nut of the sweet chestnut tree"),
+            ("Colchique", "Autumn crocus", "poisonous meadow flower of autumn"),
+            ("Cheval", "Horse", "draught animal of farm and road"),
+            ("Balsamine", "Balsam", "garden impatiens with seed pods that burst"),
+            ("Carotte", "Carrot", "sweet orange root vegetable"),
+            ("Amaranthe", "Amaranth", "plant with long-lasting crimson tassels"),
+            ("Panais", "Parsnip", "pale root vegetable, sweet after frost"),
+            ("Cuve", "Vat", "tub in which grapes ferment"),
+            ("Pomme de terre", "Potato", "tuber championed by Parmentier"),
+            ("Immortelle", "Everlasting flower", "strawflower that keeps its colour when dried"),
+            ("Potiron", "Winter squash", "large keeping gourd"),
+            ("Réséda", "Mignonette", "small fragrant garden flower"),
+            ("Âne", "Donkey", "patient beast of burden"),
+            ("Belle de nuit", "Four o'clock flower", "flower that opens in the evening"),
+            ("Citrouille", "Pumpkin", "orange gourd of the autumn harvest"),
+            ("Sarrasin", "Buckwheat", "grain grown on poor soils"),
+            ("Tournesol", "Sunflower", "tall flower grown for its seeds"),
+            ("Pressoir", "Wine press", "press that squeezes juice from grapes"),
+            ("Chanvre", "Hemp", "fibre plant for rope and cloth"),
+            ("Pêche", "Peach", "soft, downy stone fruit"),
+            ("Navet", "Turnip", "hardy root vegetable"),
+            ("Amaryllis", "Amaryllis", "bulb with large trumpet flowers"),
+            ("Bœuf", "Ox", "castrated bull used for ploughing"),
+            ("Aubergine", "Aubergine", "eggplant, a purple fruiting vegetable"),
+            ("Piment", "Chilli pepper", "hot fruit of the capsicum"),
+            ("Tomate", "Tomato", "the love apple, a New World fruit"),
+            ("Orge", "Barley", "grain for bread, beer and fodder"),
+            ("Tonneau", "Barrel", "cask in which wine is kept"),
+        ],
+        1 => &[
             // Brumaire
-            let items = [
-                "Pomme",
-                "Céleri",
-                "Poire",
-                "Betterave",
-                "Oie",
-                "Héliotrope",
-                "Figue",
-                "Scorsonère",
-                "Alisier",
-                "Charrue",
-                "Salsifis",
-                "Mâcre",
-                "Topinambour",
-                "Endive",
-                "Dindon",
-                "Chervis",
-                "Cresson",
-                "Dentelaire",
-                "Grenade",
-                "Herse",
-                "Bacchante",
-                "Azerole",
-                "Garance",
-                "Orange",
-                "Faisan",
-                "Pistache",
-                "Macjonc",
-                "Coing",
-                "Cormier",
-                "Rouleau",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        2 => {
+            ("Pomme", "Apple", "orchard fruit, also pressed for cider"),
+            ("Céleri", "Celery", "crisp-stalked vegetable"),
+            ("Poire", "Pear", "orchard fruit, also pressed for perry"),
+            ("Betterave", "Beetroot", "root grown for sugar and the table"),
+            ("Oie", "Goose", "farmyard fowl fattened for winter"),
+            ("Héliotrope", "Heliotrope", "flower that turns toward the sun"),
+            ("Figue", "Fig", "sweet fruit of Mediterranean gardens"),
+            ("Scorsonère", "Black salsify", "root vegetable with black skin"),
+            ("Alisier", "Whitebeam", "small tree with edible berries"),
+            ("Charrue", "Plough", "tool that turns the soil"),
+            ("Salsifis", "Salsify", "root vegetable with an oyster-like taste"),
+            ("Mâcre", "Water chestnut", "edible seed of a floating plant"),
+            ("Topinambour", "Jerusalem artichoke", "knobbly tuber of a sunflower"),
+            ("Endive", "Endive", "bitter salad leaf"),
+            ("Dindon", "Turkey", "large fowl from the Americas"),
+            ("Chervis", "Skirret", "old root vegetable, sweet and white"),
+            ("Cresson", "Watercress", "peppery leaf grown in running water"),
+            ("Dentelaire", "Leadwort", "plumbago, a blue-flowered shrub"),
+            ("Grenade", "Pomegranate", "fruit full of ruby seeds"),
+            ("Herse", "Harrow", "tool that breaks up ploughed soil"),
+            ("Bacchante", "Baccharis", "groundsel tree of the salt marsh"),
+            ("Azerole", "Azarole", "Mediterranean hawthorn with edible fruit"),
+            ("Garance", "Madder", "root that yields a red dye"),
+            ("Orange", "Orange", "citrus fruit of the south"),
+            ("Faisan", "Pheasant", "long-tailed game bird"),
+            ("Pistache", "Pistachio", "green nut of a Mediterranean tree"),
+            ("Macjonc", "Tuberous pea", "wild pea with edible tubers"),
+            ("Coing", "Quince", "hard fruit cooked into jelly"),
+            ("Cormier", "Service tree", "tree whose fruit is eaten bletted"),
+            ("Rouleau", "Roller", "tool that levels and firms the field"),
+        ],
+        2 => &[
             // Frimaire
-            let items = [
-                "Raiponce",
-                "Turneps",
-                "Chicorée",
-                "Nèfle",
-                "Cochon",
-                "Mâche",
-                "Chou-fleur",
-                "Miel",
-                "Genièvre",
-                "Pioche",
-                "Cire",
-                "Raifort",
-                "Cèdre",
-                "Sapin",
-                "Chevreuil",
-                "Ajonc",
-                "Cyprès",
-                "Lierre",
-                "Sabine",
-                "Hoyau",
-                "Érable à sucre",
-                "Bruyère",
-                "Roseau",
-                "Oseille",
-                "Grillon",
-                "Pignon",
-                "Liège",
-                "Truffe",
-                "Olive",
-                "Pelle",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        3 => {
+            ("Raiponce", "Rampion", "bellflower with an edible root"),
+            ("Turneps", "Turnip", "fodder turnip for livestock"),
+            ("Chicorée", "Chicory", "plant whose root is roasted as coffee"),
+            ("Nèfle", "Medlar", "fruit eaten once it softens"),
+            ("Cochon", "Pig", "farm animal kept for meat"),
+            ("Mâche", "Lamb's lettuce", "hardy winter salad leaf"),
+            ("Chou-fleur", "Cauliflower", "cabbage grown for its white head"),
+            ("Miel", "Honey", "sweetness gathered by bees"),
+            ("Genièvre", "Juniper", "shrub with aromatic berries"),
+            ("Pioche", "Pickaxe", "tool for breaking hard ground"),
+            ("Cire", "Wax", "beeswax, for candles and polish"),
+            ("Raifort", "Horseradish", "pungent root used as a condiment"),
+            ("Cèdre", "Cedar", "tall evergreen with fragrant wood"),
+            ("Sapin", "Fir", "evergreen conifer of the mountains"),
+            ("Chevreuil", "Roe deer", "small deer of the woods"),
+            ("Ajonc", "Gorse", "spiny shrub with yellow flowers"),
+            ("Cyprès", "Cypress", "dark evergreen of cemeteries"),
+            ("Lierre", "Ivy", "climbing evergreen"),
+            ("Sabine", "Savin", "juniper of the Alps"),
+            ("Hoyau", "Grub hoe", "heavy hoe for clearing ground"),
+            ("Érable à sucre", "Sugar maple", "tree tapped for sweet sap"),
+            ("Bruyère", "Heather", "low shrub of the heath"),
+            ("Roseau", "Reed", "tall grass of wetlands, used for thatch"),
+            ("Oseille", "Sorrel", "sour-leaved herb"),
+            ("Grillon", "Cricket", "chirping insect of the hearth"),
+            ("Pignon", "Pine nut", "edible seed of the stone pine"),
+            ("Liège", "Cork", "bark of the cork oak"),
+            ("Truffe", "Truffle", "prized underground fungus"),
+            ("Olive", "Olive", "fruit pressed for oil"),
+            ("Pelle", "Shovel", "tool for moving earth"),
+        ],
+        3 => &[
             // Nivôse
-            let items = [
-                "Tourbe",
-                "Houille",
-                "Bitume",
-                "Soufre",
-                "Chien",
-                "Lave",
-                "Terre végétale",
-                "Fumier",
-                "Salpêtre",
-                "Fléau",
-                "Granit",
-                "Argile",
-                "Ardoise",
-                "Grès",
-                "Lapin",
-                "Silex",
-                "Marne",
-                "Pierre à chaux",
-                "Marbre",
-                "Van",
-                "Pierre à plâtre",
-                "Sel",
-                "Fer",
-                "Cuivre",
-                "Chat",
-                "Étain",
-                "Plomb",
-                "Zinc",
-                "Mercure",
-                "Crible",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        4 => {
+            ("Tourbe", "Peat", "fuel cut from bogs"),
+            ("Houille", "Coal", "mineral fuel from the mine"),
+            ("Bitume", "Bitumen", "natural tar and asphalt"),
+            ("Soufre", "Sulphur", "yellow mineral that burns blue"),
+            ("Chien", "Dog", "faithful guardian of house and flock"),
+            ("Lave", "Lava", "molten rock from volcanoes"),
+            ("Terre végétale", "Topsoil", "fertile earth that feeds the crops"),
+            ("Fumier", "Manure", "dung that enriches the fields"),
+            ("Salpêtre", "Saltpetre", "nitre, used in gunpowder"),
+            ("Fléau", "Flail", "tool for threshing grain"),
+            ("Granit", "Granite", "hard crystalline rock"),
+            ("Argile", "Clay", "earth for bricks and pottery"),
+            ("Ardoise", "Slate", "stone split into roof tiles"),
+            ("Grès", "Sandstone", "rock of cemented sand"),
+            ("Lapin", "Rabbit", "burrowing animal bred for meat"),
+            ("Silex", "Flint", "hard stone that strikes sparks"),
+            ("Marne", "Marl", "limy clay spread on fields"),
+            ("Pierre à chaux", "Limestone", "stone burnt to make lime"),
+            ("Marbre", "Marble", "stone for sculpture"),
+            ("Van", "Winnowing basket", "basket that separates grain from chaff"),
+            ("Pierre à plâtre", "Gypsum", "stone burnt to make plaster"),
+            ("Sel", "Salt", "mineral that seasons and preserves"),
+            ("Fer", "Iron", "the most useful of metals"),
+            ("Cuivre", "Copper", "red metal for pots and wire"),
+            ("Chat", "Cat", "mouser of the house and barn"),
+            ("Étain", "Tin", "soft metal for plate and alloys"),
+            ("Plomb", "Lead", "heavy, soft grey metal"),
+            ("Zinc", "Zinc", "bluish metal used in alloys"),
+            ("Mercure", "Mercury", "quicksilver, the liquid metal"),
+            ("Crible", "Sieve", "tool for sifting grain and soil"),
+        ],
+        4 => &[
             // Pluviôse
-            let items = [
-                "Lauréole",
-                "Mousse",
-                "Fragon",
-                "Perce-neige",
-                "Taureau",
-                "Laurier-thym",
-                "Amadouvier",
-                "Mézéréon",
-                "Peuplier",
-                "Coignée",
-                "Ellébore",
-                "Brocoli",
-                "Laurier",
-                "Avelinier",
-                "Vache",
-                "Buis",
-                "Lichen",
-                "If",
-                "Pulmonaire",
-                "Serpette",
-                "Thlaspi",
-                "Thimelé",
-                "Chiendent",
-                "Trainasse",
-                "Lièvre",
-                "Guède",
-                "Noisetier",
-                "Cyclamen",
-                "Chélidoine",
-                "Traîneau",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        5 => {
+            ("Lauréole", "Spurge laurel", "evergreen shrub of the woods"),
+            ("Mousse", "Moss", "small plants that carpet damp ground"),
+            ("Fragon", "Butcher's broom", "spiny evergreen shrub"),
+            ("Perce-neige", "Snowdrop", "first flower of late winter"),
+            ("Taureau", "Bull", "sire of the herd"),
+            ("Laurier-thym", "Laurustinus", "winter-flowering viburnum"),
+            ("Amadouvier", "Tinder fungus", "bracket fungus used to catch sparks"),
+            ("Mézéréon", "Mezereon", "shrub with fragrant winter flowers"),
+            ("Peuplier", "Poplar", "fast-growing tree of riverbanks"),
+            ("Coignée", "Axe", "tool for felling trees"),
+            ("Ellébore", "Hellebore", "Christmas rose, blooming in winter"),
+            ("Brocoli", "Broccoli", "green sprouting cabbage"),
+            ("Laurier", "Bay laurel", "aromatic leaf of victors' crowns"),
+            ("Avelinier", "Hazel", "shrub that bears hazelnuts"),
+            ("Vache", "Cow", "provider of milk"),
+            ("Buis", "Box", "slow-growing evergreen for hedges"),
+            ("Lichen", "Lichen", "partnership of fungus and alga"),
+            ("If", "Yew", "long-lived evergreen with red berries"),
+            ("Pulmonaire", "Lungwort", "spotted-leaved spring flower"),
+            ("Serpette", "Pruning hook", "hooked blade for pruning vines"),
+            ("Thlaspi", "Pennycress", "small wild cress"),
+            ("Thimelé", "Flax-leaved daphne", "Mediterranean shrub"),
+            ("Chiendent", "Couch grass", "creeping grass of fields"),
+            ("Trainasse", "Knotgrass", "low weed of paths and fields"),
+            ("Lièvre", "Hare", "swift animal of the open fields"),
+            ("Guède", "Woad", "plant that gives a blue dye"),
+            ("Noisetier", "Hazel tree", "tree of hedges and copses"),
+            ("Cyclamen", "Cyclamen", "sowbread, with swept-back petals"),
+            ("Chélidoine", "Celandine", "plant with orange sap"),
+            ("Traîneau", "Sledge", "sled for hauling loads over snow"),
+        ],
+        5 => &[
             // Ventôse
-            let items = [
-                "Tussilage",
-                "Cornouiller",
-                "Violier",
-                "Troène",
-                "Bouc",
-                "Asaret",
-                "Alaterne",
-                "Violette",
-                "Marceau",
-                "Bêche",
-                "Narcisse",
-                "Orme",
-                "Fumeterre",
-                "Vélar",
-                "Chèvre",
-                "Épinard",
-                "Doronic",
-                "Mouron",
-                "Cerfeuil",
-                "Cordeau",
-                "Mandragore",
-                "Persil",
-                "Cochléaria",
-                "Pâquerette",
-                "Thon",
-                "Pissenlit",
-                "Sylvie",
-                "Capillaire",
-                "Frêne",
-                "Plantoir",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        6 => {
+            ("Tussilage", "Coltsfoot", "early yellow flower, a cough remedy"),
+            ("Cornouiller", "Dogwood", "shrub with hard wood and red berries"),
+            ("Violier", "Wallflower", "scented flower of old walls"),
+            ("Troène", "Privet", "hedge shrub"),
+            ("Bouc", "Billy goat", "male goat"),
+            ("Asaret", "Wild ginger", "low woodland plant"),
+            ("Alaterne", "Italian buckthorn", "Mediterranean evergreen shrub"),
+            ("Violette", "Violet", "small fragrant spring flower"),
+            ("Marceau", "Goat willow", "willow with catkins in early spring"),
+            ("Bêche", "Spade", "tool for digging"),
+            ("Narcisse", "Narcissus", "daffodil of spring"),
+            ("Orme", "Elm", "tall tree of avenues"),
+            ("Fumeterre", "Fumitory", "delicate weed of fields"),
+            ("Vélar", "Hedge mustard", "wild mustard of waysides"),
+            ("Chèvre", "Goat", "nanny goat, giving milk"),
+            ("Épinard", "Spinach", "leafy green vegetable"),
+            ("Doronic", "Leopard's bane", "yellow daisy of the mountains"),
+            ("Mouron", "Pimpernel", "small scarlet-flowered weed"),
+            ("Cerfeuil", "Chervil", "delicate kitchen herb"),
+            ("Cordeau", "Garden line", "cord that keeps garden rows straight"),
+            ("Mandragore", "Mandrake", "root of legend and medicine"),
+            ("Persil", "Parsley", "the commonest kitchen herb"),
+            ("Cochléaria", "Scurvy grass", "leaf eaten against scurvy"),
+            ("Pâquerette", "Daisy", "the humble lawn flower"),
+            ("Thon", "Tuna", "great fish of the sea"),
+            ("Pissenlit", "Dandelion", "yellow flower and bitter salad"),
+            ("Sylvie", "Wood anemone", "white flower of spring woods"),
+            ("Capillaire", "Maidenhair fern", "delicate fern of damp walls"),
+            ("Frêne", "Ash", "tall tree with tough wood"),
+            ("Plantoir", "Dibber", "pointed tool for planting"),
+        ],
+        6 => &[
             // Germinal
-            let items = [
-                "Primevère",
-                "Platane",
-                "Asperge",
-                "Tulipe",
-                "Poule",
-                "Bette",
-                "Bouleau",
-                "Jonquille",
-                "Aulne",
-                "Couvoir",
-                "Pervenche",
-                "Charme",
-                "Morille",
-                "Hêtre",
-                "Abeille",
-                "Laitue",
-                "Mélèze",
-                "Ciguë",
-                "Radis",
-                "Ruche",
-                "Gainier",
-                "Romaine",
-                "Marronnier",
-                "Roquette",
-                "Pigeon",
-                "Lilas",
-                "Anémone",
-                "Pensée",
-                "Myrtille",
-                "Greffoir",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        7 => {
+            ("Primevère", "Primrose", "pale yellow flower of early spring"),
+            ("Platane", "Plane tree", "shade tree with flaking bark"),
+            ("Asperge", "Asparagus", "spring shoots eaten as a delicacy"),
+            ("Tulipe", "Tulip", "bulb of bright spring flowers"),
+            ("Poule", "Hen", "laying fowl of the farmyard"),
+            ("Bette", "Chard", "leaf beet"),
+            ("Bouleau", "Birch", "white-barked tree"),
+            ("Jonquille", "Jonquil", "fragrant narcissus"),
+            ("Aulne", "Alder", "tree of wet ground"),
+            ("Couvoir", "Hatchery", "incubator where eggs hatch"),
+            ("Pervenche", "Periwinkle", "blue-flowered creeper"),
+            ("Charme", "Hornbeam", "tree for hedges and firewood"),
+            ("Morille", "Morel", "honeycombed spring mushroom"),
+            ("Hêtre", "Beech", "smooth-barked tree of the forest"),
+            ("Abeille", "Bee", "maker of honey and wax"),
+            ("Laitue", "Lettuce", "common salad leaf"),
+            ("Mélèze", "Larch", "conifer that sheds its needles"),
+            ("Ciguë", "Hemlock", "poisonous plant of Socrates"),
+            ("Radis", "Radish", "quick-growing peppery root"),
+            ("Ruche", "Beehive", "home of the honeybee"),
+            ("Gainier", "Judas tree", "tree with pink spring blossom"),
+            ("Romaine", "Romaine lettuce", "long-leaved cos lettuce"),
+            ("Marronnier", "Horse chestnut", "tree with conkers and white candles"),
+            ("Roquette", "Rocket", "peppery salad leaf"),
+            ("Pigeon", "Pigeon", "dovecote bird"),
+            ("Lilas", "Lilac", "shrub with fragrant clusters"),
+            ("Anémone", "Anemone", "windflower"),
+            ("Pensée", "Pansy", "small flower with a face"),
+            ("Myrtille", "Bilberry", "wild blueberry"),
+            ("Greffoir", "Grafting knife", "knife used to graft fruit trees"),
+        ],
+        7 => &[
             // Floréal
-            let items = [
-                "Rose",
-                "Chêne",
-                "Fougère",
-                "Aubépine",
-                "Rossignol",
-                "Ancolie",
-                "Muguet",
-                "Champignon",
-                "Hyacinthe",
-                "Râteau",
-                "Rhubarbe",
-                "Sainfoin",
-                "Bâton d'or",
-                "Chamerisier",
-                "Ver à soie",
-                "Consoude",
-                "Pimprenelle",
-                "Corbeille d'or",
-                "Arroche",
-                "Sarcloir",
-                "Statice",
-                "Fritillaire",
-                "Bourrache",
-                "Valériane",
-                "Carpe",
-                "Fusain",
-                "Civette",
-                "Buglosse",
-                "Sénevé",
-                "Houlette",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        8 => {
+            ("Rose", "Rose", "queen of flowers"),
+            ("Chêne", "Oak", "strong, long-lived tree"),
+            ("Fougère", "Fern", "feathery plant of shade"),
+            ("Aubépine", "Hawthorn", "may blossom of hedgerows"),
+            ("Rossignol", "Nightingale", "bird that sings at night"),
+            ("Ancolie", "Columbine", "flower with spurred petals"),
+            ("Muguet", "Lily of the valley", "small fragrant bells of May"),
+            ("Champignon", "Mushroom", "field mushroom"),
+            ("Hyacinthe", "Hyacinth", "fragrant spring bulb"),
+            ("Râteau", "Rake", "tool for gathering and levelling"),
+            ("Rhubarbe", "Rhubarb", "plant with sour edible stalks"),
+            ("Sainfoin", "Sainfoin", "fodder legume with pink flowers"),
+            ("Bâton d'or", "Wallflower", "golden wallflower"),
+            ("Chamerisier", "Dwarf fan palm", "Mediterranean palm"),
+            ("Ver à soie", "Silkworm", "caterpillar that spins silk"),
+            ("Consoude", "Comfrey", "healing herb with bell flowers"),
+            ("Pimprenelle", "Salad burnet", "herb with a cucumber taste"),
+            ("Corbeille d'or", "Basket of gold", "yellow alyssum of rock gardens"),
+            ("Arroche", "Orache", "mountain spinach"),
+            ("Sarcloir", "Weeding hoe", "hoe for clearing weeds"),
+            ("Statice", "Sea lavender", "statice, an everlasting flower"),
+            ("Fritillaire", "Fritillary", "snake's head flower"),
+            ("Bourrache", "Borage", "blue-flowered herb"),
+            ("Valériane", "Valerian", "soothing medicinal herb"),
+            ("Carpe", "Carp", "pond fish"),
+            ("Fusain", "Spindle tree", "shrub with pink berries"),
+            ("Civette", "Chives", "onion-flavoured herb"),
+            ("Buglosse", "Bugloss", "blue-flowered borage relative"),
+            ("Sénevé", "Mustard seed", "white mustard, small seed of great growth"),
+            ("Houlette", "Shepherd's crook", "staff of the shepherd"),
+        ],
+        8 => &[
             // Prairial
-            let items = [
-                "Luzerne",
-                "Hémérocalle",
-                "Trèfle",
-                "Angélique",
-                "Canard",
-                "Mélisse",
-                "Fromental",
-                "Martagon",
-                "Serpolet",
-                "Faux",
-                "Fraise",
-                "Bétoine",
-                "Pois",
-                "Acacia",
-                "Caille",
-                "Œillet",
-                "Sureau",
-                "Pavot",
-                "Tilleul",
-                "Fourche",
-                "Barbeau",
-                "Camomille",
-                "Chèvrefeuille",
-                "Caille-lait",
-                "Tanche",
-                "Jasmin",
-                "Verveine",
-                "Thym",
-                "Pivoine",
-                "Chariot",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        9 => {
+            ("Luzerne", "Alfalfa", "lucerne, a rich fodder crop"),
+            ("Hémérocalle", "Daylily", "lily whose flowers last a day"),
+            ("Trèfle", "Clover", "three-leaved fodder plant"),
+            ("Angélique", "Angelica", "aromatic herb, candied as a sweet"),
+            ("Canard", "Duck", "waterfowl of the farm pond"),
+            ("Mélisse", "Lemon balm", "lemon-scented herb"),
+            ("Fromental", "Oat grass", "tall meadow grass"),
+            ("Martagon", "Turk's cap lily", "martagon lily"),
+            ("Serpolet", "Wild thyme", "creeping aromatic herb"),
+            ("Faux", "Scythe", "tool for mowing hay"),
+            ("Fraise", "Strawberry", "sweet red fruit of early summer"),
+            ("Bétoine", "Betony", "wild medicinal herb"),
+            ("Pois", "Pea", "green pod vegetable"),
+            ("Acacia", "Acacia", "false acacia with scented blossom"),
+            ("Caille", "Quail", "small game bird of the fields"),
+            ("Œillet", "Carnation", "clove-scented pink"),
+            ("Sureau", "Elder", "shrub with flowers and black berries"),
+            ("Pavot", "Poppy", "red flower of the cornfield"),
+            ("Tilleul", "Linden", "lime tree whose flowers make tisane"),
+            ("Fourche", "Pitchfork", "fork for lifting hay"),
+            ("Barbeau", "Cornflower", "blue flower of the cornfield"),
+            ("Camomille", "Chamomile", "flower for a calming tea"),
+            ("Chèvrefeuille", "Honeysuckle", "fragrant climbing plant"),
+            ("Caille-lait", "Lady's bedstraw", "yellow bedstraw that curdles milk"),
+            ("Tanche", "Tench", "freshwater fish"),
+            ("Jasmin", "Jasmine", "fragrant white-flowered climber"),
+            ("Verveine", "Vervain", "sacred herb of old"),
+            ("Thym", "Thyme", "aromatic kitchen herb"),
+            ("Pivoine", "Peony", "large showy flower"),
+            ("Chariot", "Cart", "wagon for the harvest"),
+        ],
+        9 => &[
             // Messidor
-            let items = [
-                "Seigle",
-                "Avoine",
-                "Oignon",
-                "Véronique",
-                "Mulet",
-                "Romarin",
-                "Concombre",
-                "Échalote",
-                "Absinthe",
-                "Faucille",
-                "Coriandre",
-                "Artichaut",
-                "Girofle",
-                "Lavande",
-                "Chamois",
-                "Tabac",
-                "Groseille",
-                "Gesse",
-                "Cerise",
-                "Parc",
-                "Menthe",
-                "Cumin",
-                "Haricot",
-                "Orcanète",
-                "Pintade",
-                "Sauge",
-                "Ail",
-                "Vesce",
-                "Blé",
-                "Chalémie",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        10 => {
+            ("Seigle", "Rye", "hardy grain for dark bread"),
+            ("Avoine", "Oats", "grain for horses and porridge"),
+            ("Oignon", "Onion", "pungent bulb of the kitchen"),
+            ("Véronique", "Speedwell", "small blue wildflower"),
+            ("Mulet", "Mule", "offspring of donkey and horse"),
+            ("Romarin", "Rosemary", "aromatic shrub of the south"),
+            ("Concombre", "Cucumber", "cool summer vegetable"),
+            ("Échalote", "Shallot", "mild onion of the kitchen"),
+            ("Absinthe", "Wormwood", "bitter herb of absinthe"),
+            ("Faucille", "Sickle", "curved blade for reaping"),
+            ("Coriandre", "Coriander", "herb and spice"),
+            ("Artichaut", "Artichoke", "thistle with an edible heart"),
+            ("Girofle", "Clove", "dried flower bud used as spice"),
+            ("Lavande", "Lavender", "fragrant purple herb"),
+            ("Chamois", "Chamois", "goat-antelope of the mountains"),
+            ("Tabac", "Tobacco", "leaf for smoking and snuff"),
+            ("Groseille", "Redcurrant", "tart summer berry"),
+            ("Gesse", "Grass pea", "vetchling grown as fodder"),
+            ("Cerise", "Cherry", "red stone fruit of summer"),
+            ("Parc", "Sheepfold", "enclosure where flocks are penned"),
+            ("Menthe", "Mint", "cool aromatic herb"),
+            ("Cumin", "Cumin", "warm spice seed"),
+            ("Haricot", "Bean", "green bean"),
+            ("Orcanète", "Alkanet", "root that gives a red dye"),
+            ("Pintade", "Guinea fowl", "speckled farmyard bird"),
+            ("Sauge", "Sage", "herb of health"),
+            ("Ail", "Garlic", "pungent bulb"),
+            ("Vesce", "Vetch", "fodder legume"),
+            ("Blé", "Wheat", "grain for bread"),
+            ("Chalémie", "Shawm", "shepherd's reed pipe"),
+        ],
+        10 => &[
             // Thermidor
-            let items = [
-                "Épeautre",
-                "Bouillon blanc",
-                "Melon",
-                "Ivraie",
-                "Bélier",
-                "Prêle",
-                "Armoise",
-                "Carthame",
-                "Mûre",
-                "Arrosoir",
-                "Panic",
-                "Salicorne",
-                "Abricot",
-                "Basilic",
-                "Brebis",
-                "Guimauve",
-                "Lin",
-                "Amande",
-                "Gentiane",
-                "Écluse",
-                "Carline",
-                "Câprier",
-                "Lentille",
-                "Aunée",
-                "Loutre",
-                "Myrte",
-                "Colza",
-                "Lupin",
-                "Coton",
-                "Moulin",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        11 => {
+            ("Épeautre", "Spelt", "ancient wheat"),
+            ("Bouillon blanc", "Great mullein", "tall woolly-leaved plant"),
+            ("Melon", "Melon", "sweet summer fruit"),
+            ("Ivraie", "Ryegrass", "darnel, a weed of the wheat field"),
+            ("Bélier", "Ram", "male sheep"),
+            ("Prêle", "Horsetail", "jointed plant of damp ground"),
+            ("Armoise", "Mugwort", "aromatic herb of waysides"),
+            ("Carthame", "Safflower", "thistle that yields a dye and oil"),
+            ("Mûre", "Blackberry", "bramble fruit"),
+            ("Arrosoir", "Watering can", "can for watering the garden"),
+            ("Panic", "Panic grass", "millet-like grain"),
+            ("Salicorne", "Glasswort", "salt-marsh plant once burnt for soda"),
+            ("Abricot", "Apricot", "golden stone fruit"),
+            ("Basilic", "Basil", "fragrant kitchen herb"),
+            ("Brebis", "Ewe", "female sheep"),
+            ("Guimauve", "Marshmallow", "plant whose root made the sweet"),
+            ("Lin", "Flax", "plant for linen and linseed"),
+            ("Amande", "Almond", "nut of the almond tree"),
+            ("Gentiane", "Gentian", "bitter mountain root"),
+            ("Écluse", "Lock", "sluice that controls water"),
+            ("Carline", "Carline thistle", "stemless silver thistle"),
+            ("Câprier", "Caper", "flower bud pickled as a condiment"),
+            ("Lentille", "Lentil", "small edible pulse"),
+            ("Aunée", "Elecampane", "tall yellow medicinal plant"),
+            ("Loutre", "Otter", "playful river animal"),
+            ("Myrte", "Myrtle", "fragrant evergreen of Venus"),
+            ("Colza", "Rapeseed", "colza, grown for oil"),
+            ("Lupin", "Lupin", "legume with tall flower spikes"),
+            ("Coton", "Cotton", "plant whose bolls give fibre"),
+            ("Moulin", "Mill", "mill that grinds the grain"),
+        ],
+        11 => &[
             // Fructidor
-            let items = [
-                "Prune",
-                "Millet",
-                "Lycoperdon",
-                "Escourgeon",
-                "Saumon",
-                "Tubéreuse",
-                "Sucrion",
-                "Apocyn",
-                "Réglisse",
-                "Échelle",
-                "Pastèque",
-                "Fenouil",
-                "Épine vinette",
-                "Noix",
-                "Truite",
-                "Citron",
-                "Cardère",
-                "Nerprun",
-                "Tagette",
-                "Hotte",
-                "Églantier",
-                "Noisette",
-                "Houblon",
-                "Sorgho",
-                "Écrevisse",
-                "Bigarade",
-                "Verge d'or",
-                "Maïs",
-                "Marron",
-                "Panier",
-            ];
-            items.get(day - 1).unwrap_or(&"Item").to_string()
-        }
-        12 => {
+            ("Prune", "Plum", "sweet stone fruit"),
+            ("Millet", "Millet", "small-seeded grain"),
+            ("Lycoperdon", "Puffball", "round fungus that bursts into spores"),
+            ("Escourgeon", "Winter barley", "six-row barley"),
+            ("Saumon", "Salmon", "fish that swims upriver"),
+            ("Tubéreuse", "Tuberose", "heavily scented flower"),
+            ("Sucrion", "Spring barley", "two-row barley"),
+            ("Apocyn", "Dogbane", "plant with milky sap"),
+            ("Réglisse", "Liquorice", "sweet root"),
+            ("Échelle", "Ladder", "ladder for the orchard"),
+            ("Pastèque", "Watermelon", "large juicy melon"),
+            ("Fenouil", "Fennel", "aniseed-flavoured herb"),
+            ("Épine vinette", "Barberry", "thorny shrub with sour berries"),
+            ("Noix", "Walnut", "nut of the walnut tree"),
+            ("Truite", "Trout", "fish of cold streams"),
+            ("Citron", "Lemon", "sour citrus fruit"),
+            ("Cardère", "Teasel", "plant used to raise the nap of cloth"),
+            ("Nerprun", "Buckthorn", "shrub with purging berries"),
+            ("Tagette", "Marigold", "French marigold"),
+            ("Hotte", "Harvest basket", "basket carried on the back at harvest"),
+            ("Églantier", "Dog rose", "wild rose of hedges"),
+            ("Noisette", "Hazelnut", "nut of the hazel"),
+            ("Houblon", "Hops", "climber that flavours beer"),
+            ("Sorgho", "Sorghum", "tall grain of warm lands"),
+            ("Écrevisse", "Crayfish", "freshwater crustacean"),
+            ("Bigarade", "Bitter orange", "Seville orange"),
+            ("Verge d'or", "Goldenrod", "tall yellow autumn flower"),
+            ("Maïs", "Maize", "corn from the Americas"),
+            ("Marron", "Sweet chestnut", "marron, the large chestnut"),
+            ("Panier", "Basket", "basket for gathering produce"),
+        ],
+        12 => &[
             // Sans-culottides / complementary days
-            match day {
-                1 => "La Fête de la Vertu".to_string(),
-                2 => "La Fête du Génie".to_string(),
-                3 => "La Fête du Travail".to_string(),
-                4 => "La Fête de l'Opinion".to_string(),
-                5 => "La Fête des Récompenses".to_string(),
-                6 => "La Fête de la Révolution".to_string(), // leap years only
-                _ => "".to_string(),
-            }
-        }
-        _ => format!("{}", day),
+            ("La Fête de la Vertu", "Festival of Virtue", "first complementary day"),
+            ("La Fête du Génie", "Festival of Genius", "second complementary day"),
+            ("La Fête du Travail", "Festival of Labour", "third complementary day"),
+            ("La Fête de l'Opinion", "Festival of Opinion", "fourth complementary day"),
+            ("La Fête des Récompenses", "Festival of Rewards", "fifth complementary day"),
+            ("La Fête de la Révolution", "Festival of the Revolution", "leap day, sextile years only"),
+        ],
+        _ => &[],
+    };
+
+    let (name, english, description) = items.get(day - 1).copied().unwrap_or(("Item", "Item", ""));
+    FrenchRevolutionaryItem {
+        name,
+        english,
+        description,
+        category: item_category(month, day),
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,7 @@
 // gregorian.rs
 // Standard calendar
 
+use super::sun_times::sun_summary;
 use crate::models::CalendarDate;
 use crate::utils::resolve_date_format;
 use chrono::{DateTime, Local};
@@ -16,7 +17,7 @@ impl super::Calendar for GregorianCalendar {
         CalendarDate {
             system: "Gregorian".to_string(),
             date: date.format(&format_str).to_string(),
-            additional_info: None,
+            additional_info: settings.and_then(|s| sun_summary(date.date_naive(), s)),
         }
     }
 }
\ No newline at end of file
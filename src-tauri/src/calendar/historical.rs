@@ -0,0 +1,144 @@
+// historical.rs
+// Julian before the country's Gregorian reform, Gregorian after, with optional Old Style/New Style dual dating
+
+use super::julian::{absolute_from_julian, julian_from_absolute};
+use super::month_name;
+use crate::models::CalendarDate;
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+
+pub struct HistoricalCalendar;
+
+#[derive(Clone, Copy)]
+struct Reform {
+    key: &'static str,
+    name: &'static str,
+    // First day reckoned in the Gregorian calendar
+    first_gregorian: (i32, u32, u32),
+    // First year that began on 1 January, for countries that counted from 25 March (Lady Day)
+    january_new_year_from: Option<i32>,
+}
+
+const REFORMS: [Reform; 15] = [
+    Reform { key: "rome", name: "Papal States", first_gregorian: (1582, 10, 15), january_new_year_from: None },
+    Reform { key: "spain", name: "Spain", first_gregorian: (1582, 10, 15), january_new_year_from: None },
+    Reform { key: "portugal", name: "Portugal", first_gregorian: (1582, 10, 15), january_new_year_from: None },
+    Reform { key: "poland", name: "Poland", first_gregorian: (1582, 10, 15), january_new_year_from: None },
+    Reform { key: "france", name: "France", first_gregorian: (1582, 12, 20), january_new_year_from: None },
+    Reform { key: "prussia", name: "Prussia", first_gregorian: (1610, 9, 2), january_new_year_from: None },
+    Reform { key: "germany", name: "Protestant Germany", first_gregorian: (1700, 3, 1), january_new_year_from: None },
+    Reform { key: "denmark", name: "Denmark–Norway", first_gregorian: (1700, 3, 1), january_new_year_from: None },
+    Reform { key: "britain", name: "Great Britain", first_gregorian: (1752, 9, 14), january_new_year_from: Some(1752) },
+    Reform { key: "sweden", name: "Sweden", first_gregorian: (1753, 3, 1), january_new_year_from: None },
+    Reform { key: "bulgaria", name: "Bulgaria", first_gregorian: (1916, 4, 14), january_new_year_from: None },
+    Reform { key: "russia", name: "Russia", first_gregorian: (1918, 2, 14), january_new_year_from: None },
+    Reform { key: "serbia", name: "Serbia", first_gregorian: (1919, 1, 28), january_new_year_from: None },
+    Reform { key: "romania", name: "Romania", first_gregorian: (1919, 4, 14), january_new_year_from: None },
+    Reform { key: "greece", name: "Greece", first_gregorian: (1923, 3, 1), january_new_year_from: None },
+];
+
+// Resolves the "historical_country" setting: a key from REFORMS or a custom "YYYY-MM-DD" first Gregorian day
+fn resolve_reform(country: &str) -> Reform {
+    let key = country.trim().to_lowercase();
+    if let Some(reform) = REFORMS.iter().find(|r| r.key == key) {
+        return *reform;
+    }
+
+    if let Ok(first) = NaiveDate::parse_from_str(&key, "%Y-%m-%d") {
+        return Reform {
+            key: "custom",
+            name: "Custom reform",
+            first_gregorian: (first.year(), first.month(), first.day()),
+            january_new_year_from: None,
+        };
+    }
+
+    REFORMS[0]
+}
+
+// "1750/51", or "1699/1700" when the century changes
+fn format_year_pair(first: i32, second: i32) -> String {
+    if first == second {
+        first.to_string()
+    } else if first.div_euclid(100) == second.div_euclid(100) {
+        format!("{}/{:02}", first, second.rem_euclid(100))
+    } else {
+        format!("{}/{}", first, second)
+    }
+}
+
+impl super::Calendar for HistoricalCalendar {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let reform = resolve_reform(
+            settings
+                .and_then(|s| s.historical_country.as_deref())
+                .unwrap_or("rome"),
+        );
+        let dual_dating = settings.and_then(|s| s.historical_dual_dating).unwrap_or(false);
+        let new_year = settings.and_then(|s| s.historical_new_year.as_deref());
+
+        let gregorian = date.naive_local().date();
+        let abs = gregorian.num_days_from_ce() as i64;
+        let (first_y, first_m, first_d) = reform.first_gregorian;
+        let reform_abs = NaiveDate::from_ymd_opt(first_y, first_m, first_d)
+            .map(|d| d.num_days_from_ce() as i64)
+            .unwrap_or(i64::MIN);
+        let in_julian_use = abs < reform_abs;
+
+        let (julian_year, julian_month, julian_day) = julian_from_absolute(abs);
+
+        // Old Style years may begin on 25 March, so 1 January – 24 March still belongs to the previous year
+        let lady_day = match new_year {
+            Some("march25") | Some("lady_day") => in_julian_use,
+            Some("january") => false,
+            _ => reform.january_new_year_from.is_some_and(|y| julian_year < y),
+        };
+        let old_style_year = if lady_day && abs < absolute_from_julian(julian_year, 3, 25) {
+            julian_year - 1
+        } else {
+            julian_year
+        };
+
+        let date_str = if dual_dating {
+            let days = if julian_month == gregorian.month() {
+                format!("{}/{} {}", julian_day, gregorian.day(), month_name(julian_month))
+            } else {
+                format!(
+                    "{} {}/{} {}",
+                    julian_day,
+                    month_name(julian_month),
+                    gregorian.day(),
+                    month_name(gregorian.month())
+                )
+            };
+            format!("{} {}", days, format_year_pair(old_style_year, gregorian.year()))
+        } else if in_julian_use {
+            format!(
+                "{} {} {}",
+                julian_day,
+                month_name(julian_month),
+                format_year_pair(old_style_year, julian_year)
+            )
+        } else {
+            format!("{} {} {}", gregorian.day(), month_name(gregorian.month()), gregorian.year())
+        };
+
+        let style = if in_julian_use {
+            "Old Style (Julian)"
+        } else {
+            "New Style (Gregorian)"
+        };
+
+        CalendarDate {
+            system: "Historical".to_string(),
+            date: date_str,
+            additional_info: Some(format!(
+                "{} · {}, reform of {} {} {}",
+                style,
+                reform.name,
+                first_d,
+                month_name(first_m),
+                first_y
+            )),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// icelandic.rs
+// Old Icelandic (misseri) calendar: weeks counted through the summer and winter halves of a 364-day year
+
+use crate::models::CalendarDate;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Weekday};
+
+pub struct IcelandicCalendar;
+
+// (Icelandic name, English gloss); the first six months belong to summer, the rest to winter
+const MONTHS: [(&str, &str); 12] = [
+    ("Harpa", "first month of summer"),
+    ("Skerpla", "second month of summer"),
+    ("Sólmánuður", "sun month"),
+    ("Heyannir", "haymaking"),
+    ("Tvímánuður", "two months to winter"),
+    ("Haustmánuður", "autumn month"),
+    ("Gormánuður", "slaughter month"),
+    ("Ýlir", "Yule month"),
+    ("Mörsugur", "fat-sucking month"),
+    ("Þorri", "frozen snow"),
+    ("Góa", "Góa's month"),
+    ("Einmánuður", "lone month"),
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "Mánudagur", "Þriðjudagur", "Miðvikudagur", "Fimmtudagur", "Föstudagur", "Laugardagur", "Sunnudagur",
+];
+
+// First day of the given weekday on or after `from`
+fn weekday_on_or_after(from: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let ahead = (7 + weekday.num_days_from_monday() - from.weekday().num_days_from_monday()) % 7;
+    from + Duration::days(ahead as i64)
+}
+
+// Sumardagurinn fyrsti: the Thursday from 19 to 25 April
+fn first_day_of_summer(year: i32) -> NaiveDate {
+    weekday_on_or_after(NaiveDate::from_ymd_opt(year, 4, 19).unwrap(), Weekday::Thu)
+}
+
+// Fyrsti vetrardagur: winter is always six months of 30 days, so it opens 180 days before the next summer.
+// Whatever is left of the year after 26 weeks and 2 days of summer is the sumarauki.
+fn first_day_of_winter(year: i32) -> NaiveDate {
+    first_day_of_summer(year + 1) - Duration::days(180)
+}
+
+// Position of a day within its half-year; `month` is None during the aukanætur and sumarauki
+struct MisseriDay {
+    in_summer: bool,
+    offset: i64,
+    month: Option<usize>,
+    day: i64,
+    sumarauki: bool,
+}
+
+fn locate(naive: NaiveDate, summer_start: NaiveDate, winter_start: NaiveDate) -> MisseriDay {
+    if naive < winter_start {
+        let offset = (naive - summer_start).num_days();
+        // Heyannir always opens 90 days before winter; the aukanætur (and sumarauki in leap-week years) fill the gap
+        let heyannir_start = winter_start - Duration::days(90);
+        let (month, day, sumarauki) = if offset < 90 {
+            (Some((offset / 30) as usize), offset % 30 + 1, false)
+        } else if naive < heyannir_start {
+            (None, offset - 89, offset - 90 >= 4)
+        } else {
+            let into = (naive - heyannir_start).num_days();
+            (Some(3 + (into / 30) as usize), into % 30 + 1, false)
+        };
+        MisseriDay { in_summer: true, offset, month, day, sumarauki }
+    } else {
+        let offset = (naive - winter_start).num_days();
+        MisseriDay {
+            in_summer: false,
+            offset,
+            month: Some(6 + (offset / 30) as usize),
+            day: offset % 30 + 1,
+            sumarauki: false,
+        }
+    }
+}
+
+impl super::Calendar for IcelandicCalendar {
+    fn convert(&self, date: &DateTime<Local>, _settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let naive = date.naive_local().date();
+        let year = naive.year();
+
+        // The misseri year runs from one first day of summer to the next
+        let misseri_year = if naive >= first_day_of_summer(year) { year } else { year - 1 };
+        let summer_start = first_day_of_summer(misseri_year);
+        let winter_start = first_day_of_winter(misseri_year);
+
+        let MisseriDay { in_summer, offset, month, day, sumarauki } = locate(naive, summer_start, winter_start);
+        let week = offset / 7 + 1;
+        let half = if in_summer { "sumars" } else { "vetrar" };
+        let half_english = if in_summer { "summer" } else { "winter" };
+        let weekday = WEEKDAYS[naive.weekday().num_days_from_monday() as usize];
+
+        let (date_str, mut info) = match month {
+            Some(index) => {
+                let (name, gloss) = MONTHS[index];
+                (
+                    format!("{}, {}. {} · {}. vika {}", weekday, day, name, week, half),
+                    format!("{}, day {} of {} ({}), week {} of {}", naive.format("%A"), day, name, gloss, week, half_english),
+                )
+            }
+            None if sumarauki => (
+                format!("{}, Sumarauki {} · {}. vika {}", weekday, day - 4, week, half),
+                format!("{} of the sumarauki (leap week), week {} of {}", naive.format("%A"), week, half_english),
+            ),
+            None => (
+                format!("{}, Aukanætur {} · {}. vika {}", weekday, day, week, half),
+                format!("{} of the aukanætur (extra nights), week {} of {}", naive.format("%A"), week, half_english),
+            ),
+        };
+
+        let feast = if naive == summer_start {
+            Some("Sumardagurinn fyrsti (First Day of Summer)")
+        } else if naive == winter_start {
+            Some("Fyrsti vetrardagur (First Day of Winter)")
+        } else if month == Some(9) && day == 1 {
+            Some("Bóndadagur (Husband's Day)")
+        } else if month == Some(10) && day == 1 {
+            Some("Konudagur (Wife's Day)")
+        } else {
+            None
+        };
+        if let Some(feast) = feast {
+            info = format!("{} · {}", feast, info);
+        }
+
+        CalendarDate {
+            system: "Icelandic".to_string(),
+            date: date_str,
+            additional_info: Some(info),
+        }
+    }
+}
@@ -1,88 +1,65 @@
 // jewish_calendar.rs
 // Accurate Hebrew calendar conversion (Dershowitz & Reingold style).
 
-use crate::models::CalendarDate;
+use super::anniversaries::anniversary_notes;
+use super::learning::{daf_yomi, mishnah_yomit, rambam, tehillim};
+use super::zmanim::{format_zman, zmanim};
+use crate::models::{CalendarDate, UserSettings, Zmanim};
 use chrono::{DateTime, Local, NaiveDate, Datelike};
-use std::collections::HashMap;
 
-pub struct JewishCalendar {
-    // we keep a tiny cache for computed 1 Tishri dates to avoid recomputing repeatedly
-    rosh_hashanah_cache: HashMap<i32, NaiveDate>,
-}
+pub struct JewishCalendar;
 
-impl JewishCalendar {
-    pub fn new() -> Self {
-        Self {
-            rosh_hashanah_cache: HashMap::new(),
-        }
-    }
-
-    // public entry: convert a NaiveDate to a formatted hebrew date string
-    fn to_jewish_date(&mut self, gregorian: NaiveDate) -> String {
-        // compute absolute day for the gregorian date (days since Gregorian 1/1/1 = 1)
+impl super::Calendar for JewishCalendar {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let gregorian = date.naive_local().date();
         let abs = absolute_from_gregorian(gregorian.year(), gregorian.month(), gregorian.day());
-
-        // find hebrew year containing this absolute day
-        let mut year = (gregorian.year() + 3760) as i32;
-
-        // Find the Rosh Hashanah date for the year
-        let mut rh_abs = self.rosh_hashanah_naive(year).num_days_from_ce() as i64 + 1;
-
-        // Adjust the year if the date is before or after Rosh Hashanah
-        if abs < rh_abs {
-            year -= 1;
-            rh_abs = self.rosh_hashanah_naive(year).num_days_from_ce() as i64 + 1;
-        } else {
-            let next_rh_abs = self.rosh_hashanah_naive(year + 1).num_days_from_ce() as i64 + 1;
-            if abs >= next_rh_abs {
-                year += 1;
-                rh_abs = self.rosh_hashanah_naive(year).num_days_from_ce() as i64 + 1;
-            }
+        let hebrew = hebrew_from_absolute(abs);
+        let date_str = format_hebrew_date(hebrew, settings);
+
+        // Israel keeps one day of each festival where the diaspora keeps two, which also shifts the readings
+        let israel = settings.and_then(|s| s.jewish_israel_schedule).unwrap_or(false);
+        let mut info = observances(abs, israel);
+        if let Some(settings) = settings {
+            info.extend(anniversary_notes(settings, gregorian));
         }
-
-        // Now compute day within Hebrew year
-        let day_of_year = (abs - rh_abs) as u32; // 0-based
-        let month_lengths = get_hebrew_month_lengths(year);
-
-        let mut rem = day_of_year;
-        for (len, eng, heb) in month_lengths.iter() {
-            if rem < *len {
-                let day = rem + 1;
-                let heb_day = hebrew_numeral(day);
-                return format!(
-                    "{} {} {} ({} {})",
-                    day, eng, year, heb_day, heb
-                );
+        if let (Some(settings), Some(candles)) = (settings, candle_lighting(abs, israel)) {
+            let times = zmanim(gregorian, settings);
+            match (candles, times) {
+                (Candles::BeforeSunset, Ok(Zmanim { candle_lighting: Some(candles), sunset: Some(sunset), .. })) => {
+                    info.push(format!(
+                        "Candle lighting {} · Sunset {}",
+                        format_zman(candles, settings),
+                        format_zman(sunset, settings)
+                    ));
+                }
+                (Candles::AfterNightfall, Ok(Zmanim { tzeit_hakochavim: Some(tzeit), .. })) => {
+                    info.push(format!("Candle lighting after {}", format_zman(tzeit, settings)));
+                }
+                _ => {}
             }
-            rem -= *len;
+        }
+        if let Some(portion) = weekly_portion(abs, israel) {
+            info.push(portion);
         }
 
-        format!("Date beyond year boundary: {} days since Rosh Hashanah", day_of_year)
-    }
-
-    // Helper: get 1 Tishri as NaiveDate for given hebrew year (cached)
-    fn rosh_hashanah_naive(&mut self, year: i32) -> NaiveDate {
-        if let Some(&d) = self.rosh_hashanah_cache.get(&year) {
-            return d;
+        // Study cycles chosen in settings, in the order listed there
+        let cycles = settings.and_then(|s| s.jewish_learning_cycles.as_ref());
+        for cycle in cycles.into_iter().flatten() {
+            let entry = match cycle.as_str() {
+                "daf_yomi" => daf_yomi(abs),
+                "mishnah_yomit" => mishnah_yomit(abs),
+                "rambam" | "rambam_1" => rambam(abs, 1),
+                "rambam_3" => rambam(abs, 3),
+                "tehillim" => Some(tehillim(hebrew.day, days_in_hebrew_month(hebrew.year, hebrew.month))),
+                _ => None,
+            };
+            info.extend(entry);
         }
-        let abs = hebrew_calendar_elapsed_days(year);
-        let naive = gregorian_from_absolute(abs);
-        self.rosh_hashanah_cache.insert(year, naive);
-        naive
-    }
-}
 
-impl super::Calendar for JewishCalendar {
-    fn convert(&self, date: &DateTime<Local>, _settings: Option<&crate::models::UserSettings>) -> CalendarDate {
-        let mut tmp = JewishCalendar {
-            rosh_hashanah_cache: self.rosh_hashanah_cache.clone(),
-        };
-        let gregorian_date = date.naive_local().date();
-        let date_str = tmp.to_jewish_date(gregorian_date);
         CalendarDate {
             system: "Jewish".to_string(),
             date: date_str,
-            additional_info: None,
+            additional_info: if info.is_empty() { None } else { Some(info.join(" · ")) },
         }
     }
 }
@@ -90,93 +67,505 @@ impl super::Calendar for JewishCalendar {
 // Hebrew epoch constant
 const HEBREW_EPOCH: i64 = -1373429;
 
+// Months are numbered from Nisan as in Dershowitz & Reingold, although the year itself begins with Tishrei
+const NISAN: u32 = 1;
+const IYAR: u32 = 2;
+const SIVAN: u32 = 3;
+const TAMMUZ: u32 = 4;
+const AV: u32 = 5;
+const ELUL: u32 = 6;
+const TISHREI: u32 = 7;
+pub const CHESHVAN: u32 = 8;
+pub const KISLEV: u32 = 9;
+pub const TEVET: u32 = 10;
+pub const SHEVAT: u32 = 11;
+pub const ADAR: u32 = 12; // Adar I in leap years
+pub const ADAR_II: u32 = 13;
+
+// Day of week of an absolute day, 0 = Sunday
+const SUNDAY: i64 = 0;
+const MONDAY: i64 = 1;
+const TUESDAY: i64 = 2;
+const FRIDAY: i64 = 5;
+const SHABBAT: i64 = 6;
+
+fn day_of_week(abs: i64) -> i64 {
+    abs.rem_euclid(7)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HebrewDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
 // Compute the days (absolute) of Rosh Hashanah for a given Hebrew year.
 fn hebrew_calendar_elapsed_days(year: i32) -> i64 {
-    let y = year - 1;
-    let months_elapsed = (235 * (y / 19))
-        + 12 * (y % 19)
-        + ((7 * (y % 19) + 1) / 19);
+    let y = (year - 1) as i64;
+    let months_elapsed = 235 * (y / 19) + 12 * (y % 19) + (7 * (y % 19) + 1) / 19;
+
+    // Molad of Tishrei, counted from the molad of creation (BaHaRaD: day 2, 5 hours, 204 parts)
+    let parts_elapsed = 204 + 793 * (months_elapsed % 1080);
+    let hours_elapsed = 5 + 12 * months_elapsed + 793 * (months_elapsed / 1080) + parts_elapsed / 1080;
+    let day = 1 + 29 * months_elapsed + hours_elapsed / 24;
+    let parts = 1080 * (hours_elapsed % 24) + parts_elapsed % 1080;
+
+    // Postponements: molad zaken, GaTaRaD and BeTUTaKPaT
+    let alternative_day = if parts >= 19440
+        || (day % 7 == 2 && parts >= 9924 && !is_jewish_leap_year(year))
+        || (day % 7 == 1 && parts >= 16789 && is_jewish_leap_year(year - 1))
+    {
+        day + 1
+    } else {
+        day
+    };
+
+    // Lo ADU Rosh: never on Sunday, Wednesday or Friday
+    let elapsed = if matches!(alternative_day % 7, 0 | 3 | 5) {
+        alternative_day + 1
+    } else {
+        alternative_day
+    };
+
+    HEBREW_EPOCH + elapsed + 1
+}
+
+// Return (month, length, English name, Hebrew name) for each month, starting with Tishrei
+fn get_hebrew_month_lengths(year: i32) -> Vec<(u32, u32, &'static str, &'static str)> {
+    let is_leap = is_jewish_leap_year(year);
+    let days_in_year = hebrew_calendar_elapsed_days(year + 1) - hebrew_calendar_elapsed_days(year);
 
-    let parts_elapsed = 204 + 793 * months_elapsed + 1080 * (12 * months_elapsed);
-    let day = 1 + 29 * months_elapsed + (parts_elapsed / 25920);
-    let parts = parts_elapsed % 25920;
+    let (cheshvan_len, kislev_len) = match days_in_year {
+        353 | 383 => (29, 29),
+        354 | 384 => (29, 30),
+        355 | 385 => (30, 30),
+        _ => (29, 30),
+    };
 
-    let mut day = day;
+    let mut months: Vec<(u32, u32, &'static str, &'static str)> = vec![
+        (TISHREI, 30, "Tishrei", "תשרי"),
+        (CHESHVAN, cheshvan_len, "Cheshvan", "חשון"),
+        (KISLEV, kislev_len, "Kislev", "כסלו"),
+        (TEVET, 29, "Tevet", "טבת"),
+        (SHEVAT, 30, "Shevat", "שבט"),
+    ];
 
-    if parts >= 19440 {
-        day += 1;
+    if is_leap {
+        months.push((ADAR, 30, "Adar I", "אדר א׳"));
+        months.push((ADAR_II, 29, "Adar II", "אדר ב׳"));
+    } else {
+        months.push((ADAR, 29, "Adar", "אדר"));
     }
 
-    let mut day_of_week = (day % 7) as i32;
+    months.push((NISAN, 30, "Nisan", "ניסן"));
+    months.push((IYAR, 29, "Iyar", "אייר"));
+    months.push((SIVAN, 30, "Sivan", "סיון"));
+    months.push((TAMMUZ, 29, "Tammuz", "תמוז"));
+    months.push((AV, 30, "Av", "אב"));
+    months.push((ELUL, 29, "Elul", "אלול"));
+
+    months
+}
+
+// Leap year in 19-year cycle
+pub fn is_jewish_leap_year(year: i32) -> bool {
+    matches!(year.rem_euclid(19), 0 | 3 | 6 | 8 | 11 | 14 | 17)
+}
+
+// The last month of the year, in which Purim falls
+pub fn last_adar(year: i32) -> u32 {
+    if is_jewish_leap_year(year) { ADAR_II } else { ADAR }
+}
+
+pub fn hebrew_month_name(year: i32, month: u32) -> (&'static str, &'static str) {
+    get_hebrew_month_lengths(year)
+        .into_iter()
+        .find(|(m, _, _, _)| *m == month)
+        .map(|(_, _, eng, heb)| (eng, heb))
+        .unwrap_or(("Unknown", ""))
+}
+
+pub fn days_in_hebrew_month(year: i32, month: u32) -> u32 {
+    get_hebrew_month_lengths(year)
+        .into_iter()
+        .find(|(m, _, _, _)| *m == month)
+        .map(|(_, len, _, _)| len)
+        .unwrap_or(30)
+}
 
-    if day_of_week == 2 && parts >= 9924 && !is_jewish_leap_year(year) {
-        day += 1;
-        day_of_week = (day % 7) as i32;
+fn absolute_from_hebrew(year: i32, month: u32, day: u32) -> i64 {
+    let mut abs = hebrew_calendar_elapsed_days(year) + day as i64 - 1;
+    for (m, len, _, _) in get_hebrew_month_lengths(year) {
+        if m == month {
+            break;
+        }
+        abs += len as i64;
     }
+    abs
+}
 
-    if day_of_week == 1 && parts >= 16789 && is_jewish_leap_year(year - 1) {
-        day += 1;
-        day_of_week = (day % 7) as i32;
+fn hebrew_from_absolute(abs: i64) -> HebrewDate {
+    // Rosh Hashanah always falls in September or October, so the Hebrew year is one of two
+    let mut year = gregorian_from_absolute(abs).year() + 3760;
+    if abs >= hebrew_calendar_elapsed_days(year + 1) {
+        year += 1;
     }
 
-    if day_of_week == 0 || day_of_week == 3 || day_of_week == 5 {
-        day += 1;
+    let mut rem = abs - hebrew_calendar_elapsed_days(year);
+    let months = get_hebrew_month_lengths(year);
+    for (month, len, _, _) in months.iter() {
+        if rem < *len as i64 {
+            return HebrewDate { year, month: *month, day: rem as u32 + 1 };
+        }
+        rem -= *len as i64;
     }
-    
-    // The previous implementation was likely off by one.
-    // The "absolute day" must be 1-based, and the calculation
-    // can result in a 0-based offset. The simplest fix is to add 1 here.
-    let final_day = day + 1;
 
-    HEBREW_EPOCH + final_day as i64
+    HebrewDate { year, month: ELUL, day: 29 }
 }
 
-// Return month lengths for the year
-fn get_hebrew_month_lengths(year: i32) -> Vec<(u32, &'static str, &'static str)> {
-    let is_leap = is_jewish_leap_year(year);
-    let days_in_year = (hebrew_calendar_elapsed_days(year + 1) - hebrew_calendar_elapsed_days(year)) as i64;
+pub fn hebrew_from_gregorian(date: NaiveDate) -> HebrewDate {
+    hebrew_from_absolute(absolute_from_gregorian(date.year(), date.month(), date.day()))
+}
 
-    let (cheshvan_len, kislev_len) = match days_in_year {
-        353 | 383 => (29, 29),
-        354 | 384 => (29, 30),
-        355 | 385 => (30, 30),
-        _ => (29, 30),
+// A day past the end of a short month runs on into the next one, as the anniversary rules expect
+pub fn gregorian_from_hebrew(date: HebrewDate) -> NaiveDate {
+    gregorian_from_absolute(absolute_from_hebrew(date.year, date.month, date.day))
+}
+
+// "7 Cheshvan 5787 (ז׳ חשון ה׳ תשפ״ז)", or only the Hebrew when jewish_hebrew_date is set
+pub fn format_hebrew_date(date: HebrewDate, settings: Option<&UserSettings>) -> String {
+    let (eng, heb) = hebrew_month_name(date.year, date.month);
+    let style = GematriaStyle::from_settings(settings);
+    let hebrew = format!(
+        "{} {} {}",
+        hebrew_numeral(date.day, style),
+        heb,
+        hebrew_numeral(date.year as u32, style)
+    );
+    if settings.and_then(|s| s.jewish_hebrew_date).unwrap_or(false) {
+        hebrew
+    } else {
+        format!("{} {} {} ({})", date.day, eng, date.year, hebrew)
+    }
+}
+
+/* -------------------------
+    Observances
+    ------------------------- */
+
+// Festivals, fasts, Rosh Chodesh, Chanukah and the Omer for one civil day
+fn observances(abs: i64, israel: bool) -> Vec<String> {
+    let HebrewDate { year, month, day } = hebrew_from_absolute(abs);
+    let purim_month = last_adar(year);
+    let mut found: Vec<String> = Vec::new();
+
+    let festival = match (month, day) {
+        (ELUL, 29) => Some("Erev Rosh Hashanah"),
+        (TISHREI, 1) => Some("Rosh Hashanah I"),
+        (TISHREI, 2) => Some("Rosh Hashanah II"),
+        (TISHREI, 9) => Some("Erev Yom Kippur"),
+        (TISHREI, 10) => Some("Yom Kippur"),
+        (TISHREI, 14) => Some("Erev Sukkot"),
+        (TISHREI, 15) => Some("Sukkot I"),
+        (TISHREI, 16) if !israel => Some("Sukkot II"),
+        (TISHREI, 16..=20) => Some("Sukkot (Chol HaMoed)"),
+        (TISHREI, 21) => Some("Hoshana Rabbah"),
+        (TISHREI, 22) if israel => Some("Shmini Atzeret / Simchat Torah"),
+        (TISHREI, 22) => Some("Shmini Atzeret"),
+        (TISHREI, 23) if !israel => Some("Simchat Torah"),
+        (KISLEV, 24) => Some("Erev Chanukah"),
+        (SHEVAT, 15) => Some("Tu BiShvat"),
+        (ADAR, 14) if purim_month == ADAR_II => Some("Purim Katan"),
+        (m, 14) if m == purim_month => Some("Purim"),
+        (m, 15) if m == purim_month => Some("Shushan Purim"),
+        (NISAN, 14) => Some("Erev Pesach"),
+        (NISAN, 15) => Some("Pesach I"),
+        (NISAN, 16) if !israel => Some("Pesach II"),
+        (NISAN, 16..=20) => Some("Pesach (Chol HaMoed)"),
+        (NISAN, 21) => Some("Pesach VII"),
+        (NISAN, 22) if !israel => Some("Pesach VIII"),
+        (IYAR, 18) => Some("Lag BaOmer"),
+        (IYAR, 28) if year >= 5727 => Some("Yom Yerushalayim"),
+        (SIVAN, 5) => Some("Erev Shavuot"),
+        (SIVAN, 6) if israel => Some("Shavuot"),
+        (SIVAN, 6) => Some("Shavuot I"),
+        (SIVAN, 7) if !israel => Some("Shavuot II"),
+        (AV, 15) => Some("Tu B'Av"),
+        _ => None,
     };
+    if let Some(festival) = festival {
+        found.push(festival.to_string());
+    }
 
-    let mut months: Vec<(u32, &'static str, &'static str)> = Vec::new();
-    months.push((30, "Tishrei", "תשרי"));
-    months.push((cheshvan_len as u32, "Cheshvan", "חשון"));
-    months.push((kislev_len as u32, "Kislev", "כסלו"));
-    months.push((29, "Tevet", "טבת"));
-    months.push((30, "Shevat", "שבט"));
+    // Fasts that fall on Shabbat are postponed to Sunday; those kept before Purim and Pesach move back to Thursday
+    let fasts = [
+        (TISHREI, 3, "Tzom Gedaliah", 1),
+        (TEVET, 10, "Asara B'Tevet", 1),
+        (purim_month, 13, "Ta'anit Esther", -2),
+        (NISAN, 14, "Ta'anit Bechorot", -2),
+        (TAMMUZ, 17, "Shiva Asar B'Tammuz", 1),
+        (AV, 9, "Tisha B'Av", 1),
+    ];
+    for (fast_month, fast_day, name, shift) in fasts {
+        let mut fast = absolute_from_hebrew(year, fast_month, fast_day);
+        if day_of_week(fast) == SHABBAT {
+            fast += shift;
+        }
+        if fast == abs {
+            found.push(name.to_string());
+        }
+    }
 
-    if is_leap {
-        months.push((30, "Adar I", "אדר א׳"));
-        months.push((29, "Adar II", "אדר ב׳"));
+    // Israeli national days move away from Shabbat so their ceremonies need not break it
+    if year >= 5711 {
+        let nisan_27 = absolute_from_hebrew(year, NISAN, 27);
+        let yom_hashoah = match day_of_week(nisan_27) {
+            FRIDAY => nisan_27 - 1,
+            SUNDAY => nisan_27 + 1,
+            _ => nisan_27,
+        };
+        if yom_hashoah == abs {
+            found.push("Yom HaShoah".to_string());
+        }
+    }
+    if year >= 5708 {
+        let iyar_5 = absolute_from_hebrew(year, IYAR, 5);
+        let yom_haatzmaut = match day_of_week(iyar_5) {
+            FRIDAY => iyar_5 - 1,
+            SHABBAT => iyar_5 - 2,
+            MONDAY if year >= 5764 => iyar_5 + 1,
+            _ => iyar_5,
+        };
+        if yom_haatzmaut - 1 == abs {
+            found.push("Yom HaZikaron".to_string());
+        } else if yom_haatzmaut == abs {
+            found.push("Yom HaAtzmaut".to_string());
+        }
+    }
+
+    // The 30th of a month is the first day of Rosh Chodesh for the next one
+    if day == 30 {
+        let (next, _) = hebrew_month_name(year, hebrew_from_absolute(abs + 1).month);
+        found.push(format!("Rosh Chodesh {}", next));
+    } else if day == 1 && month != TISHREI {
+        let (name, _) = hebrew_month_name(year, month);
+        found.push(format!("Rosh Chodesh {}", name));
+    }
+
+    let chanukah_day = abs - absolute_from_hebrew(year, KISLEV, 25) + 1;
+    if (1..=8).contains(&chanukah_day) {
+        found.push(format!("Chanukah day {}", chanukah_day));
+    }
+
+    // Counted from the second night of Pesach; the count for a day is the one said the evening before
+    let omer_day = abs - absolute_from_hebrew(year, NISAN, 16) + 1;
+    if (1..=49).contains(&omer_day) {
+        found.push(format!("Omer day {}", omer_day));
+    }
+
+    found
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Candles {
+    BeforeSunset,
+    // From a flame already burning, once Shabbat or the first day of a festival is out
+    AfterNightfall,
+}
+
+// Candles are lit before Shabbat and before the first day of each festival (and its closing days); on the
+// eve of a second festival day, or of a festival that follows Shabbat, only after nightfall
+fn candle_lighting(abs: i64, israel: bool) -> Option<Candles> {
+    if day_of_week(abs) == FRIDAY {
+        return Some(Candles::BeforeSunset);
+    }
+    let tomorrow = hebrew_from_absolute(abs + 1);
+    let festival_begins = matches!(
+        (tomorrow.month, tomorrow.day),
+        (TISHREI, 1) | (TISHREI, 10) | (TISHREI, 15) | (TISHREI, 22) | (NISAN, 15) | (NISAN, 21) | (SIVAN, 6)
+    );
+    let second_day = match (tomorrow.month, tomorrow.day) {
+        (TISHREI, 2) => true,
+        (TISHREI, 16) | (TISHREI, 23) | (NISAN, 16) | (NISAN, 22) | (SIVAN, 7) => !israel,
+        _ => false,
+    };
+    if second_day || (festival_begins && day_of_week(abs) == SHABBAT) {
+        Some(Candles::AfterNightfall)
+    } else if festival_begins {
+        Some(Candles::BeforeSunset)
     } else {
-        months.push((29, "Adar", "אדר"));
+        None
     }
+}
 
-    months.push((30, "Nisan", "ניסן"));
-    months.push((29, "Iyar", "אייר"));
-    months.push((30, "Sivan", "סיון"));
-    months.push((29, "Tammuz", "תמוז"));
-    months.push((30, "Av", "אב"));
-    months.push((29, "Elul", "אלול"));
+/* -------------------------
+    Weekly Torah portion
+    ------------------------- */
 
-    months
+const PARSHIYOT: [&str; 54] = [
+    "Bereshit", "Noach", "Lech-Lecha", "Vayera", "Chayei Sara", "Toldot", "Vayetzei", "Vayishlach",
+    "Vayeshev", "Miketz", "Vayigash", "Vayechi", "Shemot", "Vaera", "Bo", "Beshalach", "Yitro",
+    "Mishpatim", "Terumah", "Tetzaveh", "Ki Tisa", "Vayakhel", "Pekudei", "Vayikra", "Tzav", "Shmini",
+    "Tazria", "Metzora", "Achrei Mot", "Kedoshim", "Emor", "Behar", "Bechukotai", "Bamidbar", "Nasso",
+    "Beha'alotcha", "Sh'lach", "Korach", "Chukat", "Balak", "Pinchas", "Matot", "Masei", "Devarim",
+    "Vaetchanan", "Eikev", "Re'eh", "Shoftim", "Ki Teitzei", "Ki Tavo", "Nitzavim", "Vayeilech",
+    "Ha'azinu", "Vezot Haberakhah",
+];
+
+// Indices into PARSHIYOT: anchors of the schedule, and the first halves of portions that may be read together
+const VAYAKHEL: usize = 21;
+const TZAV: usize = 24;
+const TAZRIA: usize = 26;
+const METZORA: usize = 27;
+const ACHREI_MOT: usize = 28;
+const BEHAR: usize = 31;
+const BAMIDBAR: usize = 33;
+const CHUKAT: usize = 38;
+const MATOT: usize = 41;
+const DEVARIM: usize = 43;
+const VAETCHANAN: usize = 44;
+const NITZAVIM: usize = 50;
+const VAYEILECH: usize = 51;
+const HAAZINU: usize = 52;
+
+// Festival reading that displaces the weekly portion when the day falls on Shabbat
+fn shabbat_festival(date: HebrewDate, israel: bool) -> Option<&'static str> {
+    match (date.month, date.day) {
+        (TISHREI, 1) | (TISHREI, 2) => Some("Rosh Hashanah"),
+        (TISHREI, 10) => Some("Yom Kippur"),
+        (TISHREI, 15) => Some("Sukkot"),
+        (TISHREI, 16) if !israel => Some("Sukkot"),
+        (TISHREI, 16..=21) => Some("Chol HaMoed Sukkot"),
+        (TISHREI, 22) => Some("Shmini Atzeret"),
+        (NISAN, 15) | (NISAN, 21) => Some("Pesach"),
+        (NISAN, 16) | (NISAN, 22) if !israel => Some("Pesach"),
+        (NISAN, 16..=20) => Some("Chol HaMoed Pesach"),
+        (SIVAN, 6) => Some("Shavuot"),
+        (SIVAN, 7) if !israel => Some("Shavuot"),
+        _ => None,
+    }
 }
 
-// Leap year in 19-year cycle
-fn is_jewish_leap_year(year: i32) -> bool {
-    matches!(year % 19, 0 | 3 | 6 | 8 | 11 | 14 | 17)
+// Spread portions first..=last over `count` Shabbatot, joining pairs (named by their first portion)
+// in order of preference until they fit; None when no such arrangement exists
+fn fill_portions(first: usize, last: usize, count: usize, pairs: &[usize]) -> Option<Vec<String>> {
+    let needed = (last + 1).checked_sub(first + count)?;
+    let joined: Vec<usize> = pairs
+        .iter()
+        .copied()
+        .filter(|p| *p >= first && *p < last)
+        .take(needed)
+        .collect();
+    if joined.len() < needed {
+        return None;
+    }
+
+    let mut portions = Vec::new();
+    let mut i = first;
+    while i <= last {
+        if joined.contains(&i) {
+            portions.push(format!("{}-{}", PARSHIYOT[i], PARSHIYOT[i + 1]));
+            i += 2;
+        } else {
+            portions.push(PARSHIYOT[i].to_string());
+            i += 1;
+        }
+    }
+    Some(portions)
+}
+
+// Like fill_portions, but up to a preferred anchor that moves on when there are more Shabbatot than
+// portions and back when joining every pair still leaves too few; also returns the anchor reached
+fn fill_to_anchor(first: usize, preferred: usize, count: usize, pairs: &[usize]) -> Option<(usize, Vec<String>)> {
+    let mut last = preferred;
+    while last + 1 < first + count {
+        last += 1;
+    }
+    loop {
+        if let Some(portions) = fill_portions(first, last, count, pairs) {
+            return Some((last, portions));
+        }
+        if last <= first {
+            return None;
+        }
+        last -= 1;
+    }
+}
+
+// Reading for every Shabbat of a Hebrew year. The cycle is pinned to Tzav (Metzora in a leap year)
+// before Pesach and Bamidbar before Shavuot where the count of Shabbatot allows, and always to Devarim
+// before Tisha B'Av and Nitzavim before Rosh Hashanah; each stretch joins portions as needed to fit.
+fn shabbat_readings(year: i32, israel: bool) -> Option<Vec<(i64, String)>> {
+    let rosh_hashanah = hebrew_calendar_elapsed_days(year);
+    let next_rosh_hashanah = hebrew_calendar_elapsed_days(year + 1);
+    let shmini_atzeret = absolute_from_hebrew(year, TISHREI, 22);
+
+    let mut readings: Vec<(i64, String)> = Vec::new();
+    let mut weekly: Vec<i64> = Vec::new();
+    let mut shabbat = rosh_hashanah + (SHABBAT - day_of_week(rosh_hashanah)).rem_euclid(7);
+    while shabbat < next_rosh_hashanah {
+        let date = hebrew_from_absolute(shabbat);
+        if let Some(festival) = shabbat_festival(date, israel) {
+            readings.push((shabbat, format!("Shabbat {}", festival)));
+        } else if shabbat < shmini_atzeret {
+            // Vayeilech waits for Shabbat Shuva when it was not joined to Nitzavim the year before
+            let separate = matches!(day_of_week(rosh_hashanah), MONDAY | TUESDAY);
+            let portion = if date.day < 10 && separate { VAYEILECH } else { HAAZINU };
+            readings.push((shabbat, format!("Parashat {}", PARSHIYOT[portion])));
+        } else {
+            weekly.push(shabbat);
+        }
+        shabbat += 7;
+    }
+
+    let before = |limit: i64| weekly.iter().filter(|s| **s < limit).count();
+    let before_pesach = before(absolute_from_hebrew(year, NISAN, 15));
+    let before_shavuot = before(absolute_from_hebrew(year, SIVAN, 6));
+    let through_tisha_bav = before(absolute_from_hebrew(year, AV, 10));
+
+    let (anchor, winter) = fill_to_anchor(
+        0,
+        if is_jewish_leap_year(year) { METZORA } else { TZAV },
+        before_pesach,
+        &[VAYAKHEL, TAZRIA],
+    )?;
+    let (anchor, spring) = fill_to_anchor(anchor + 1, BAMIDBAR, before_shavuot - before_pesach, &[TAZRIA, ACHREI_MOT, BEHAR])?;
+    let summer = fill_portions(anchor + 1, DEVARIM, through_tisha_bav - before_shavuot, &[MATOT, CHUKAT])?;
+
+    // Nitzavim and Vayeilech are read together unless next year has a Shabbat Shuva to take Vayeilech
+    let last = if matches!(day_of_week(next_rosh_hashanah), MONDAY | TUESDAY) { NITZAVIM } else { VAYEILECH };
+    let elul = fill_portions(VAETCHANAN, last, weekly.len() - through_tisha_bav, &[NITZAVIM])?;
+
+    let portions = [winter, spring, summer, elul].concat();
+    if portions.len() != weekly.len() {
+        return None;
+    }
+    readings.extend(
+        weekly
+            .into_iter()
+            .zip(portions)
+            .map(|(day, portion)| (day, format!("Parashat {}", portion))),
+    );
+    readings.sort_by_key(|(day, _)| *day);
+    Some(readings)
+}
+
+// The reading for the coming Shabbat (or today, on Shabbat)
+fn weekly_portion(abs: i64, israel: bool) -> Option<String> {
+    let shabbat = abs + (SHABBAT - day_of_week(abs)).rem_euclid(7);
+    let year = hebrew_from_absolute(shabbat).year;
+    shabbat_readings(year, israel)?
+        .into_iter()
+        .find(|(day, _)| *day == shabbat)
+        .map(|(_, reading)| reading)
 }
 
 /* -------------------------
     Helpers: absolute <-> gregorian
     ------------------------- */
 
-fn absolute_from_gregorian(year: i32, month: u32, day: u32) -> i64 {
+pub fn absolute_from_gregorian(year: i32, month: u32, day: u32) -> i64 {
     let y = year as i64;
     let d = day as i64;
 
@@ -231,38 +620,162 @@ fn gregorian_from_absolute(abs: i64) -> NaiveDate {
     Display helpers
     ------------------------- */
 
-fn hebrew_numeral(n: u32) -> String {
-    match n {
-        1 => "א׳".to_string(),
-        2 => "ב׳".to_string(),
-        3 => "ג׳".to_string(),
-        4 => "ד׳".to_string(),
-        5 => "ה׳".to_string(),
-        6 => "ו׳".to_string(),
-        7 => "ז׳".to_string(),
-        8 => "ח׳".to_string(),
-        9 => "ט׳".to_string(),
-        10 => "י׳".to_string(),
-        11 => "י״א".to_string(),
-        12 => "י״ב".to_string(),
-        13 => "י״ג".to_string(),
-        14 => "י״ד".to_string(),
-        15 => "ט״ו".to_string(),
-        16 => "ט״ז".to_string(),
-        17 => "י״ז".to_string(),
-        18 => "י״ח".to_string(),
-        19 => "י״ט".to_string(),
-        20 => "כ׳".to_string(),
-        21 => "כ״א".to_string(),
-        22 => "כ״ב".to_string(),
-        23 => "כ״ג".to_string(),
-        24 => "כ״ד".to_string(),
-        25 => "כ״ה".to_string(),
-        26 => "כ״ו".to_string(),
-        27 => "כ״ז".to_string(),
-        28 => "כ״ח".to_string(),
-        29 => "כ״ט".to_string(),
-        30 => "ל׳".to_string(),
-        _ => n.to_string(),
-    }
-}
\ No newline at end of file
+// Letter values from 400 down to 1
+const GEMATRIA_LETTERS: [(u32, char); 22] = [
+    (400, 'ת'), (300, 'ש'), (200, 'ר'), (100, 'ק'),
+    (90, 'צ'), (80, 'פ'), (70, 'ע'), (60, 'ס'), (50, 'נ'), (40, 'מ'), (30, 'ל'), (20, 'כ'), (10, 'י'),
+    (9, 'ט'), (8, 'ח'), (7, 'ז'), (6, 'ו'), (5, 'ה'), (4, 'ד'), (3, 'ג'), (2, 'ב'), (1, 'א'),
+];
+
+// How numbers are written in gematria; see `GematriaStyle::from_settings`
+#[derive(Clone, Copy)]
+pub struct GematriaStyle {
+    pub thousands: bool,
+    pub ascii: bool,
+}
+
+impl GematriaStyle {
+    pub fn from_settings(settings: Option<&UserSettings>) -> Self {
+        GematriaStyle {
+            thousands: !settings.and_then(|s| s.jewish_hide_thousands).unwrap_or(false),
+            ascii: settings.and_then(|s| s.jewish_gematria_punctuation.as_deref()) == Some("ascii"),
+        }
+    }
+}
+
+// Letters for 1-999, greedily from the largest value; 15 and 16 are written 9+6 and 9+7 so as not to
+// spell the divine name
+fn gematria_letters(mut n: u32) -> String {
+    let mut letters = String::new();
+    while n >= 100 {
+        let &(value, letter) = GEMATRIA_LETTERS.iter().find(|(value, _)| *value <= n).unwrap();
+        letters.push(letter);
+        n -= value;
+    }
+    if n == 15 || n == 16 {
+        letters.push('ט');
+        n -= 9;
+    }
+    for &(value, letter) in GEMATRIA_LETTERS.iter().skip(4) {
+        if n >= value {
+            letters.push(letter);
+            n -= value;
+        }
+    }
+    letters
+}
+
+// A geresh after a single letter, gershayim before the last of several
+fn punctuate(letters: &str, style: GematriaStyle) -> String {
+    let (geresh, gershayim) = if style.ascii { ("'", "\"") } else { ("׳", "״") };
+    let mut chars: Vec<char> = letters.chars().collect();
+    match chars.pop() {
+        Some(last) if !chars.is_empty() => format!("{}{}{}", chars.into_iter().collect::<String>(), gershayim, last),
+        Some(last) => format!("{}{}", last, geresh),
+        None => String::new(),
+    }
+}
+
+// Any positive number in Hebrew letters: 15 → ט״ו, 5786 → ה׳ תשפ״ו (or תשפ״ו without the thousands)
+pub fn hebrew_numeral(n: u32, style: GematriaStyle) -> String {
+    let (thousands, rest) = (n / 1000, n % 1000);
+    let mut parts = Vec::new();
+    // The thousands are marked by a geresh after their letters
+    if thousands > 0 && (style.thousands || rest == 0) {
+        let geresh = if style.ascii { "'" } else { "׳" };
+        parts.push(format!("{}{}", gematria_letters(thousands), geresh));
+    }
+    if rest > 0 {
+        parts.push(punctuate(&gematria_letters(rest), style));
+    }
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abs(year: i32, month: u32, day: u32) -> i64 {
+        absolute_from_gregorian(year, month, day)
+    }
+
+    #[test]
+    fn converts_known_dates() {
+        let cases = [
+            ((2024, 3, 24), HebrewDate { year: 5784, month: ADAR_II, day: 14 }),
+            ((2025, 9, 23), HebrewDate { year: 5786, month: TISHREI, day: 1 }),
+            ((2026, 10, 18), HebrewDate { year: 5787, month: CHESHVAN, day: 7 }),
+            ((2000, 1, 1), HebrewDate { year: 5760, month: TEVET, day: 23 }),
+        ];
+        for ((year, month, day), hebrew) in cases {
+            let gregorian = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+            assert_eq!(hebrew_from_gregorian(gregorian), hebrew);
+            assert_eq!(gregorian_from_hebrew(hebrew), gregorian);
+        }
+    }
+
+    #[test]
+    fn finds_observances() {
+        let cases = [
+            ((2025, 4, 13), "Pesach I"),
+            ((2024, 12, 26), "Chanukah day 1"),
+            // 9 Av 5779 fell on Shabbat and was kept on Sunday
+            ((2019, 8, 11), "Tisha B'Av"),
+            // 13 Adar II 5784 fell on Shabbat and was kept on Thursday
+            ((2024, 3, 21), "Ta'anit Esther"),
+            // 5 Iyar 5785 fell on Shabbat and was kept on Thursday
+            ((2025, 4, 30), "Yom HaZikaron"),
+            ((2025, 5, 1), "Yom HaAtzmaut"),
+        ];
+        for ((year, month, day), expected) in cases {
+            let found = observances(abs(year, month, day), false);
+            assert!(found.iter().any(|o| o == expected), "{}-{}-{}: {:?}", year, month, day, found);
+        }
+    }
+
+    #[test]
+    fn lights_candles_after_nightfall_when_the_eve_is_holy() {
+        let cases = [
+            // Erev Pesach 5785 was Shabbat
+            ((2025, 4, 12), false, Some(Candles::AfterNightfall)),
+            ((2025, 4, 13), false, Some(Candles::AfterNightfall)),
+            ((2025, 4, 13), true, None),
+            // Erev Shavuot 5784 was a Tuesday
+            ((2024, 6, 11), false, Some(Candles::BeforeSunset)),
+            ((2024, 6, 12), false, Some(Candles::AfterNightfall)),
+            // The first day of Rosh Hashanah 5786 was a Tuesday
+            ((2025, 9, 23), true, Some(Candles::AfterNightfall)),
+            ((2025, 9, 24), false, None),
+            ((2026, 10, 16), false, Some(Candles::BeforeSunset)),
+        ];
+        for ((year, month, day), israel, expected) in cases {
+            assert_eq!(candle_lighting(abs(year, month, day), israel), expected, "{}-{}-{}", year, month, day);
+        }
+    }
+
+    #[test]
+    fn schedules_weekly_portions() {
+        let cases = [
+            ((2022, 4, 23), false, "Pesach"),
+            ((2022, 4, 23), true, "Achrei Mot"),
+            ((2022, 7, 30), false, "Matot-Masei"),
+            ((2022, 7, 30), true, "Masei"),
+            ((2023, 3, 18), false, "Vayakhel-Pekudei"),
+            ((2024, 9, 28), false, "Nitzavim-Vayeilech"),
+            ((2025, 10, 18), false, "Bereshit"),
+            ((2026, 10, 17), false, "Noach"),
+        ];
+        for ((year, month, day), israel, expected) in cases {
+            let portion = weekly_portion(abs(year, month, day), israel).unwrap();
+            assert!(portion.ends_with(expected), "{}-{}-{}: {}", year, month, day, portion);
+        }
+    }
+
+    #[test]
+    fn writes_gematria() {
+        let style = GematriaStyle { thousands: true, ascii: false };
+        assert_eq!(hebrew_numeral(15, style), "ט״ו");
+        assert_eq!(hebrew_numeral(5786, style), "ה׳ תשפ״ו");
+        assert_eq!(hebrew_numeral(5786, GematriaStyle { thousands: false, ascii: true }), "תשפ\"ו");
+    }
+}
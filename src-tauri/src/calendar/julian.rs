@@ -1,20 +1,136 @@
 // julian.rs
-// Offsets Gregorian date by 13 days
+// Exact Julian calendar date through absolute day numbers
 
+use super::month_name;
 use crate::models::CalendarDate;
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Datelike, Local};
 
 pub struct JulianCalendar;
 
 impl super::Calendar for JulianCalendar {
     fn convert(&self, date: &DateTime<Local>, _settings: Option<&crate::models::UserSettings>) -> CalendarDate {
         let gregorian_date = date.naive_local().date();
-        let julian_offset = 13; // Approximate offset for 21st century
-        let julian_date = gregorian_date - chrono::Duration::days(julian_offset);
+        let abs = gregorian_date.num_days_from_ce() as i64;
+        let (year, month, day) = julian_from_absolute(abs);
+
+        // Both calendars share the seven-day week, so the weekday carries over unchanged
+        let year_str = if year < 1 {
+            format!("{} BC", 1 - year)
+        } else {
+            year.to_string()
+        };
+        let date_str = format!(
+            "{}, {} {:02}, {}",
+            gregorian_date.format("%A"),
+            month_name(month),
+            day,
+            year_str
+        );
+
+        let offset = julian_offset(year, month, day);
+        let offset_str = match offset {
+            0 => "Same day as Gregorian".to_string(),
+            n if n > 0 => format!("{} days behind Gregorian", n),
+            n => format!("{} days ahead of Gregorian", -n),
+        };
+
         CalendarDate {
             system: "Julian".to_string(),
-            date: julian_date.format("%B %d, %Y").to_string(),
-            additional_info: None,
+            date: date_str,
+            additional_info: Some(offset_str),
+        }
+    }
+}
+
+/* -------------------------
+    Helpers: absolute <-> julian
+    Absolute days share the Jewish module's numbering (Gregorian 1/1/1 = 1),
+    which is also chrono's num_days_from_ce. Years are astronomical (0 = 1 BC).
+    ------------------------- */
+
+// Julian 1/1/1 falls on Gregorian 30 December, year 0
+const JULIAN_EPOCH: i64 = -1;
+
+pub fn is_julian_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+pub fn days_in_julian_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => if is_julian_leap_year(year) { 29 } else { 28 },
+        _ => 30,
+    }
+}
+
+pub fn absolute_from_julian(year: i32, month: u32, day: u32) -> i64 {
+    let y = year as i64 - 1;
+    let correction = if month <= 2 {
+        0
+    } else if is_julian_leap_year(year) {
+        -1
+    } else {
+        -2
+    };
+
+    JULIAN_EPOCH - 1 + 365 * y + y.div_euclid(4) + (367 * month as i64 - 362) / 12 + correction + day as i64
+}
+
+// Days the Gregorian calendar runs ahead of the Julian on a given Julian date;
+// the gap grows by one at each Julian 29 February in a century year not divisible by 400
+pub fn julian_offset(year: i32, month: u32, day: u32) -> i32 {
+    let y = if month < 2 || (month == 2 && day < 29) { year - 1 } else { year };
+    y.div_euclid(100) - y.div_euclid(400) - 2
+}
+
+// Convert absolute day back to a Julian (year, month, day)
+pub fn julian_from_absolute(abs: i64) -> (i32, u32, u32) {
+    let year = (4 * (abs - JULIAN_EPOCH) + 1464).div_euclid(1461) as i32;
+    let prior_days = abs - absolute_from_julian(year, 1, 1);
+    let correction = if abs < absolute_from_julian(year, 3, 1) {
+        0
+    } else if is_julian_leap_year(year) {
+        1
+    } else {
+        2
+    };
+    let month = ((12 * (prior_days + correction) + 373) / 367) as u32;
+    let day = (abs - absolute_from_julian(year, month, 1) + 1) as u32;
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn converts_known_dates() {
+        for ((jy, jm, jd), (gy, gm, gd), offset) in [
+            ((1582, 10, 4), (1582, 10, 14), 10),
+            ((1700, 2, 28), (1700, 3, 10), 10),
+            ((1700, 2, 29), (1700, 3, 11), 11),
+            ((1900, 2, 28), (1900, 3, 12), 12),
+            ((1900, 2, 29), (1900, 3, 13), 13),
+            ((1900, 3, 1), (1900, 3, 14), 13),
+            ((2024, 12, 25), (2025, 1, 7), 13),
+        ] {
+            let abs = NaiveDate::from_ymd_opt(gy, gm, gd).unwrap().num_days_from_ce() as i64;
+            assert_eq!(absolute_from_julian(jy, jm, jd), abs, "{}-{}-{}", jy, jm, jd);
+            assert_eq!(julian_from_absolute(abs), (jy, jm, jd), "{}-{}-{}", gy, gm, gd);
+            assert_eq!(julian_offset(jy, jm, jd), offset, "{}-{}-{}", jy, jm, jd);
+        }
+    }
+
+    #[test]
+    fn round_trips_every_day() {
+        let start = NaiveDate::from_ymd_opt(-500, 1, 1).unwrap().num_days_from_ce() as i64;
+        let end = NaiveDate::from_ymd_opt(2500, 12, 31).unwrap().num_days_from_ce() as i64;
+        for abs in start..=end {
+            let (year, month, day) = julian_from_absolute(abs);
+            assert!(day >= 1 && day <= days_in_julian_month(year, month), "{}", abs);
+            assert_eq!(absolute_from_julian(year, month, day), abs, "{}", abs);
         }
     }
-}
\ No newline at end of file
+}
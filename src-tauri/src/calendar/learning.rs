@@ -0,0 +1,273 @@
+// learning.rs
+// Daily study cycles: Daf Yomi, Mishnah Yomit, Rambam and the monthly Tehillim division
+
+use super::jewish::absolute_from_gregorian;
+
+/* -------------------------
+    Daf Yomi
+    ------------------------- */
+
+// (tractate, last page), counting from page 2; first_page covers the three that start later
+const BAVLI: [(&str, u32); 40] = [
+    ("Berakhot", 64), ("Shabbat", 157), ("Eruvin", 105), ("Pesachim", 121), ("Shekalim", 22),
+    ("Yoma", 88), ("Sukkah", 56), ("Beitzah", 40), ("Rosh Hashanah", 35), ("Taanit", 31),
+    ("Megillah", 32), ("Moed Katan", 29), ("Chagigah", 27), ("Yevamot", 122), ("Ketubot", 112),
+    ("Nedarim", 91), ("Nazir", 66), ("Sotah", 49), ("Gittin", 90), ("Kiddushin", 82),
+    ("Bava Kamma", 119), ("Bava Metzia", 119), ("Bava Batra", 176), ("Sanhedrin", 113), ("Makkot", 24),
+    ("Shevuot", 49), ("Avodah Zarah", 76), ("Horayot", 14), ("Zevachim", 120), ("Menachot", 110),
+    ("Chullin", 142), ("Bekhorot", 61), ("Arakhin", 34), ("Temurah", 34), ("Keritot", 28),
+    ("Meilah", 22), ("Kinnim", 4), ("Tamid", 9), ("Middot", 5), ("Niddah", 73),
+];
+
+// Kinnim, Tamid and Middot are printed within Meilah's pagination, so their pages run on from it
+fn first_page(tractate: usize) -> u32 {
+    match BAVLI[tractate].0 {
+        "Kinnim" => 23,
+        "Tamid" => 26,
+        "Middot" => 34,
+        _ => 2,
+    }
+}
+
+// The first cycle began on 11 September 1923; from the eighth cycle (24 June 1975) Shekalim is
+// studied with the 22 pages of the Vilna edition instead of 13
+pub fn daf_yomi(abs: i64) -> Option<String> {
+    let start = absolute_from_gregorian(1923, 9, 11);
+    let shekalim_change = absolute_from_gregorian(1975, 6, 24);
+    if abs < start {
+        return None;
+    }
+
+    let shekalim_pages = if abs >= shekalim_change { 22 } else { 13 };
+    let last_page = |(name, last): &(&str, u32)| if *name == "Shekalim" { shekalim_pages } else { *last };
+    let cycle_days: i64 = BAVLI.iter().map(|t| last_page(t) as i64 - 1).sum();
+    let epoch = if abs >= shekalim_change { shekalim_change } else { start };
+    let mut day = (abs - epoch) % cycle_days;
+
+    for (index, tractate) in BAVLI.iter().enumerate() {
+        let count = last_page(tractate) as i64 - 1;
+        if day < count {
+            return Some(format!("Daf Yomi: {} {}", tractate.0, first_page(index) + day as u32));
+        }
+        day -= count;
+    }
+    None
+}
+
+/* -------------------------
+    Mishnah Yomit
+    ------------------------- */
+
+// Mishnayot in each chapter, tractate by tractate
+const MISHNAH: [(&str, &[u32]); 63] = [
+    ("Berakhot", &[5, 8, 6, 7, 5, 8, 5, 8, 5]),
+    ("Peah", &[6, 8, 8, 11, 8, 11, 8, 9]),
+    ("Demai", &[4, 5, 6, 7, 11, 12, 8]),
+    ("Kilayim", &[9, 11, 7, 9, 8, 9, 8, 6, 10]),
+    ("Sheviit", &[8, 10, 10, 10, 9, 6, 7, 11, 9, 9]),
+    ("Terumot", &[10, 6, 9, 13, 9, 6, 7, 12, 7, 12, 10]),
+    ("Maasrot", &[8, 8, 10, 6, 8]),
+    ("Maaser Sheni", &[7, 10, 13, 12, 15]),
+    ("Challah", &[9, 8, 10, 11]),
+    ("Orlah", &[9, 17, 9]),
+    ("Bikkurim", &[11, 11, 12, 5]),
+    ("Shabbat", &[11, 7, 6, 2, 4, 10, 4, 7, 7, 6, 6, 6, 7, 4, 3, 8, 8, 3, 6, 5, 3, 6, 5, 5]),
+    ("Eruvin", &[10, 6, 9, 11, 9, 10, 11, 11, 4, 15]),
+    ("Pesachim", &[7, 8, 8, 9, 10, 6, 13, 8, 11, 9]),
+    ("Shekalim", &[7, 5, 4, 9, 6, 6, 7, 8]),
+    ("Yoma", &[8, 7, 11, 6, 7, 8, 5, 9]),
+    ("Sukkah", &[11, 9, 15, 10, 8]),
+    ("Beitzah", &[10, 10, 8, 7, 7]),
+    ("Rosh Hashanah", &[9, 9, 8, 9]),
+    ("Taanit", &[7, 10, 9, 8]),
+    ("Megillah", &[11, 6, 6, 10]),
+    ("Moed Katan", &[10, 5, 9]),
+    ("Chagigah", &[8, 7, 8]),
+    ("Yevamot", &[4, 10, 10, 13, 6, 6, 6, 6, 6, 9, 7, 6, 13, 9, 10, 7]),
+    ("Ketubot", &[10, 10, 9, 12, 9, 7, 10, 8, 9, 6, 6, 4, 11]),
+    ("Nedarim", &[4, 5, 11, 8, 6, 10, 9, 7, 10, 8, 12]),
+    ("Nazir", &[7, 10, 7, 7, 7, 11, 4, 2, 5]),
+    ("Sotah", &[9, 6, 8, 5, 5, 4, 8, 7, 15]),
+    ("Gittin", &[6, 7, 8, 9, 9, 7, 9, 10, 10]),
+    ("Kiddushin", &[10, 10, 13, 14]),
+    ("Bava Kamma", &[4, 6, 11, 9, 7, 6, 7, 7, 12, 10]),
+    ("Bava Metzia", &[8, 11, 12, 12, 11, 8, 11, 9, 13, 6]),
+    ("Bava Batra", &[6, 14, 8, 9, 11, 8, 4, 8, 10, 8]),
+    ("Sanhedrin", &[6, 5, 8, 5, 5, 6, 11, 7, 6, 6, 6]),
+    ("Makkot", &[10, 8, 16]),
+    ("Shevuot", &[7, 5, 11, 13, 5, 7, 8, 6]),
+    ("Eduyot", &[14, 10, 12, 12, 7, 3, 9, 7]),
+    ("Avodah Zarah", &[9, 7, 10, 12, 12]),
+    ("Avot", &[18, 16, 18, 22, 23, 11]),
+    ("Horayot", &[5, 7, 8]),
+    ("Zevachim", &[4, 5, 6, 6, 8, 7, 6, 12, 7, 8, 8, 6, 8, 10]),
+    ("Menachot", &[4, 5, 7, 5, 9, 7, 6, 7, 9, 9, 9, 5, 11]),
+    ("Chullin", &[7, 10, 7, 7, 5, 7, 6, 6, 8, 4, 2, 5]),
+    ("Bekhorot", &[7, 9, 4, 10, 6, 12, 7, 10, 8]),
+    ("Arakhin", &[4, 6, 5, 4, 6, 5, 5, 7, 8]),
+    ("Temurah", &[6, 3, 5, 4, 6, 5, 6]),
+    ("Keritot", &[7, 6, 10, 3, 8, 9]),
+    ("Meilah", &[4, 9, 8, 6, 5, 6]),
+    ("Tamid", &[4, 5, 9, 3, 6, 4, 3]),
+    ("Middot", &[9, 6, 8, 7, 4]),
+    ("Kinnim", &[4, 5, 6]),
+    ("Kelim", &[9, 8, 8, 4, 11, 4, 6, 11, 8, 8, 9, 8, 8, 8, 6, 8, 17, 9, 10, 7, 3, 10, 5, 17, 9, 9, 12, 10, 8, 4]),
+    ("Oholot", &[8, 7, 7, 3, 7, 7, 6, 6, 16, 7, 9, 8, 6, 7, 10, 5, 5, 10]),
+    ("Negaim", &[6, 5, 8, 11, 5, 8, 5, 10, 3, 10, 12, 7, 12, 13]),
+    ("Parah", &[4, 5, 11, 4, 9, 5, 12, 11, 9, 6, 9, 11]),
+    ("Tahorot", &[9, 8, 8, 13, 9, 10, 9, 9, 9, 8]),
+    ("Mikvaot", &[8, 10, 4, 5, 6, 11, 7, 5, 7, 8]),
+    ("Niddah", &[7, 7, 7, 7, 9, 14, 5, 4, 11, 8]),
+    ("Makhshirin", &[6, 11, 8, 10, 11, 8]),
+    ("Zavim", &[6, 4, 3, 7, 12]),
+    ("Tevul Yom", &[5, 8, 6, 7]),
+    ("Yadayim", &[5, 4, 5, 8]),
+    ("Oktzin", &[6, 10, 12]),
+];
+
+// (tractate, chapter, mishnah) of the n-th mishnah in the order of study
+fn nth_mishnah(mut n: u32) -> (&'static str, usize, u32) {
+    for (tractate, chapters) in MISHNAH.iter() {
+        for (chapter, count) in chapters.iter().enumerate() {
+            if n < *count {
+                return (tractate, chapter + 1, n + 1);
+            }
+            n -= count;
+        }
+    }
+    ("Oktzin", 3, 12)
+}
+
+// Two mishnayot a day since 20 May 1947
+pub fn mishnah_yomit(abs: i64) -> Option<String> {
+    let start = absolute_from_gregorian(1947, 5, 20);
+    if abs < start {
+        return None;
+    }
+
+    let total: u32 = MISHNAH.iter().flat_map(|(_, chapters)| chapters.iter()).sum();
+    let first = ((abs - start) * 2 % total as i64) as u32;
+    let (tractate_a, chapter_a, mishnah_a) = nth_mishnah(first);
+    let (tractate_b, chapter_b, mishnah_b) = nth_mishnah(first + 1);
+
+    let range = if tractate_a != tractate_b {
+        format!("{} {}:{} - {} {}:{}", tractate_a, chapter_a, mishnah_a, tractate_b, chapter_b, mishnah_b)
+    } else if chapter_a != chapter_b {
+        format!("{} {}:{}-{}:{}", tractate_a, chapter_a, mishnah_a, chapter_b, mishnah_b)
+    } else {
+        format!("{} {}:{}-{}", tractate_a, chapter_a, mishnah_a, mishnah_b)
+    };
+    Some(format!("Mishnah Yomit: {}", range))
+}
+
+/* -------------------------
+    Rambam
+    ------------------------- */
+
+// Sections of the Mishneh Torah with their chapters, preceded by the introduction and list of
+// commandments that open the cycle
+const MISHNEH_TORAH: [(&str, u32); 87] = [
+    ("Transmission of the Oral Law", 4), ("Positive Commandments", 5), ("Negative Commandments", 5),
+    ("Overview of the Mishneh Torah", 3),
+    // Madda
+    ("Yesodei HaTorah", 10), ("Deot", 7), ("Talmud Torah", 7), ("Avodah Zarah", 12), ("Teshuvah", 10),
+    // Ahavah
+    ("Kriat Shema", 4), ("Tefillah", 15), ("Tefillin, Mezuzah and Sefer Torah", 10), ("Tzitzit", 3),
+    ("Berachot", 11), ("Milah", 3),
+    // Zemanim
+    ("Shabbat", 30), ("Eruvin", 8), ("Shevitat Asor", 3), ("Shevitat Yom Tov", 8), ("Chametz uMatzah", 8),
+    ("Shofar, Sukkah and Lulav", 8), ("Shekalim", 4), ("Kiddush HaChodesh", 19), ("Taaniyot", 5),
+    ("Megillah and Chanukah", 4),
+    // Nashim
+    ("Ishut", 25), ("Gerushin", 13), ("Yibbum and Chalitzah", 8), ("Naarah Betulah", 3), ("Sotah", 4),
+    // Kedushah
+    ("Issurei Biah", 22), ("Maachalot Asurot", 17), ("Shechitah", 14),
+    // Haflaah
+    ("Shevuot", 12), ("Nedarim", 13), ("Nezirut", 10), ("Arachin and Charamin", 8),
+    // Zeraim
+    ("Kilayim", 10), ("Matnot Aniyim", 10), ("Terumot", 15), ("Maaser", 14), ("Maaser Sheni and Neta Revai", 11),
+    ("Bikkurim", 12), ("Shemittah and Yovel", 13),
+    // Avodah
+    ("Beit HaBechirah", 8), ("Klei HaMikdash", 10), ("Biat HaMikdash", 9), ("Issurei Mizbeach", 7),
+    ("Maaseh HaKorbanot", 19), ("Temidin uMusafin", 10), ("Pesulei HaMukdashin", 19),
+    ("Avodat Yom HaKippurim", 5), ("Meilah", 8),
+    // Korbanot
+    ("Korban Pesach", 10), ("Chagigah", 3), ("Bechorot", 8), ("Shegagot", 15), ("Mechussarei Kapparah", 5),
+    ("Temurah", 4),
+    // Taharah
+    ("Tumat Met", 25), ("Parah Adumah", 15), ("Tumat Tzaraat", 16), ("Metamei Mishkav uMoshav", 13),
+    ("Shear Avot HaTumot", 20), ("Tumat Ochalin", 16), ("Kelim", 28), ("Mikvaot", 11),
+    // Nezikin
+    ("Nizkei Mamon", 14), ("Geneivah", 9), ("Gezeilah vaAvedah", 18), ("Chovel uMazzik", 8),
+    ("Rotzeach uShmirat Nefesh", 13),
+    // Kinyan
+    ("Mechirah", 30), ("Zechiyah uMattanah", 12), ("Shechenim", 14), ("Sheluchin veShutafin", 10), ("Avadim", 9),
+    // Mishpatim
+    ("Sechirut", 13), ("Sheelah uPikkadon", 8), ("Malveh veLoveh", 27), ("Toen veNitan", 16), ("Nachalot", 11),
+    // Shoftim
+    ("Sanhedrin", 26), ("Edut", 22), ("Mamrim", 7), ("Evel", 14), ("Melachim uMilchamot", 12),
+];
+
+fn nth_rambam_chapter(mut n: u32) -> (&'static str, u32) {
+    for (section, chapters) in MISHNEH_TORAH.iter() {
+        if n < *chapters {
+            return (section, n + 1);
+        }
+        n -= chapters;
+    }
+    ("Melachim uMilchamot", 12)
+}
+
+// One or three chapters a day, both cycles counted from 29 April 1984
+pub fn rambam(abs: i64, chapters_per_day: u32) -> Option<String> {
+    let start = absolute_from_gregorian(1984, 4, 29);
+    if abs < start {
+        return None;
+    }
+
+    let total: u32 = MISHNEH_TORAH.iter().map(|(_, chapters)| chapters).sum();
+    let cycle_days = (total / chapters_per_day) as i64;
+    let first = ((abs - start) % cycle_days) as u32 * chapters_per_day;
+
+    // Consecutive chapters of one section are shown as a range: "Deot 6-7, Talmud Torah 1"
+    let mut groups: Vec<(&str, u32, u32)> = Vec::new();
+    for n in first..first + chapters_per_day {
+        let (section, chapter) = nth_rambam_chapter(n);
+        match groups.last_mut() {
+            Some((last, _, end)) if *last == section => *end = chapter,
+            _ => groups.push((section, chapter, chapter)),
+        }
+    }
+    let parts: Vec<String> = groups
+        .into_iter()
+        .map(|(section, from, to)| {
+            if from == to {
+                format!("{} {}", section, from)
+            } else {
+                format!("{} {}-{}", section, from, to)
+            }
+        })
+        .collect();
+    Some(format!("Rambam: {}", parts.join(", ")))
+}
+
+/* -------------------------
+    Tehillim
+    ------------------------- */
+
+// The book divided over the days of the Hebrew month
+const TEHILLIM: [&str; 30] = [
+    "1-9", "10-17", "18-22", "23-28", "29-34", "35-38", "39-43", "44-48", "49-54", "55-59",
+    "60-65", "66-68", "69-71", "72-76", "77-78", "79-82", "83-87", "88-89", "90-96", "97-103",
+    "104-105", "106-107", "108-112", "113-118", "119:1-96", "119:97-176", "120-134", "135-139", "140-144", "145-150",
+];
+
+// In a 29-day month the last day also takes the 30th day's psalms
+pub fn tehillim(day: u32, month_length: u32) -> String {
+    let index = (day.clamp(1, 30) - 1) as usize;
+    if day == 29 && month_length == 29 {
+        format!("Tehillim {}, {}", TEHILLIM[28], TEHILLIM[29])
+    } else {
+        format!("Tehillim {}", TEHILLIM[index])
+    }
+}
@@ -0,0 +1,508 @@
+// liturgical.rs
+// Christian liturgical year: Easter by the Gregorian and Julian computus, moveable feasts, seasons and colours
+
+use super::julian::{absolute_from_julian, julian_from_absolute};
+use super::month_name;
+use crate::models::{CalendarDate, UserSettings};
+use crate::utils::ordinal;
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+
+pub struct LiturgicalCalendar;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tradition {
+    RomanCatholic,
+    Anglican,
+    Lutheran,
+    Orthodox,
+}
+
+use Tradition::*;
+
+impl Tradition {
+    fn from_settings(settings: Option<&UserSettings>) -> Self {
+        match settings.and_then(|s| s.liturgical_tradition.as_deref()) {
+            Some("anglican") => Anglican,
+            Some("lutheran") => Lutheran,
+            Some("orthodox") => Orthodox,
+            _ => RomanCatholic,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            RomanCatholic => "Roman Catholic",
+            Anglican => "Anglican",
+            Lutheran => "Lutheran",
+            Orthodox => "Eastern Orthodox",
+        }
+    }
+}
+
+const WEEKDAYS: [&str; 7] = ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+
+// A day's place in the liturgical year
+struct LiturgicalDay {
+    title: String,
+    season: &'static str,
+    colour: &'static str,
+}
+
+fn day(title: impl Into<String>, season: &'static str, colour: &'static str) -> LiturgicalDay {
+    LiturgicalDay { title: title.into(), season, colour }
+}
+
+/* -------------------------
+    Computus
+    ------------------------- */
+
+// Anonymous Gregorian algorithm (Meeus, Astronomical Algorithms ch. 8)
+pub fn gregorian_easter(year: i32) -> NaiveDate {
+    let (a, b, c) = (year % 19, year / 100, year % 100);
+    let (d, e) = (b / 4, b % 4);
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+}
+
+// Meeus' Julian algorithm; the Julian date of Pascha is returned as its Gregorian equivalent
+pub fn julian_easter(year: i32) -> NaiveDate {
+    let (a, b, c) = (year.rem_euclid(4), year.rem_euclid(7), year.rem_euclid(19));
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let month = (d + e + 114) / 31;
+    let day = (d + e + 114) % 31 + 1;
+    from_absolute(absolute_from_julian(year, month as u32, day as u32))
+}
+
+/* -------------------------
+    Helpers
+    ------------------------- */
+
+fn absolute(date: NaiveDate) -> i64 {
+    date.num_days_from_ce() as i64
+}
+
+fn from_absolute(abs: i64) -> NaiveDate {
+    NaiveDate::from_num_days_from_ce_opt(abs as i32).unwrap()
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> i64 {
+    absolute(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+}
+
+// Absolute days count Sunday as 0 mod 7
+fn sunday_on_or_before(abs: i64) -> i64 {
+    abs - abs.rem_euclid(7)
+}
+
+// The Sunday between 27 November and 3 December
+fn advent_sunday(year: i32) -> i64 {
+    sunday_on_or_before(ymd(year, 12, 24)) - 21
+}
+
+fn format_date(date: NaiveDate) -> String {
+    format!("{} {} {}", date.day(), month_name(date.month()), date.year())
+}
+
+// "28th Sunday in Ordinary Time" or "Saturday of the 28th Week in Ordinary Time"
+fn numbered_week(abs: i64, week: i64, of: &str) -> String {
+    match abs.rem_euclid(7) {
+        0 => format!("{} Sunday {}", ordinal(week), of),
+        weekday => format!("{} of the {} Week {}", WEEKDAYS[weekday as usize], ordinal(week), of),
+    }
+}
+
+// Weekdays outside the seasons take their name from the Sunday before: "Tuesday, week of the 5th Sunday after Trinity"
+fn week_of_sunday(abs: i64, sunday_title: String) -> String {
+    match abs.rem_euclid(7) {
+        0 => sunday_title,
+        weekday => {
+            let article = if sunday_title.starts_with(|c: char| c.is_ascii_digit()) { "the " } else { "" };
+            format!("{}, week of {}{}", WEEKDAYS[weekday as usize], article, sunday_title)
+        }
+    }
+}
+
+/* -------------------------
+    Western traditions
+    ------------------------- */
+
+fn western_day(abs: i64, tradition: Tradition) -> LiturgicalDay {
+    let date = from_absolute(abs);
+    let year = date.year();
+    let weekday = abs.rem_euclid(7);
+    let sunday = sunday_on_or_before(abs);
+    let easter = absolute(gregorian_easter(year));
+    let ash_wednesday = easter - 46;
+    let pentecost = easter + 49;
+    let advent = advent_sunday(year);
+    let (purple, holy_week) = match tradition {
+        RomanCatholic => ("Violet", "Red"),
+        Anglican => ("Purple", "Red"),
+        _ => ("Purple", "Scarlet"),
+    };
+
+    // Advent and the twelve days of Christmas, which straddle the civil new year
+    if abs >= advent && abs < ymd(year, 12, 25) {
+        let week = (sunday - advent) / 7 + 1;
+        let colour = match tradition {
+            RomanCatholic if week == 3 && weekday == 0 => "Rose",
+            Lutheran => "Blue",
+            _ => purple,
+        };
+        return day(numbered_week(abs, week, "of Advent"), "Advent", colour);
+    }
+    let christmas = if abs >= ymd(year, 12, 25) { ymd(year, 12, 25) } else { ymd(year - 1, 12, 25) };
+    let epiphany = ymd(from_absolute(christmas).year() + 1, 1, 6);
+    let baptism = sunday_on_or_before(epiphany) + 7;
+    if abs < epiphany {
+        let title = match (abs - christmas, weekday) {
+            (0, _) => "Christmas Day".to_string(),
+            (1..=6, 0) if tradition == RomanCatholic => "Feast of the Holy Family".to_string(),
+            (1..=6, 0) => "1st Sunday after Christmas".to_string(),
+            (7, _) => january_first(tradition).to_string(),
+            (_, 0) => "2nd Sunday after Christmas".to_string(),
+            (n, _) => format!("{} Day of Christmas", ordinal(n + 1)),
+        };
+        return day(title, "Christmas", "White");
+    }
+    if abs == epiphany {
+        return day("Epiphany of the Lord", if tradition == RomanCatholic { "Christmas" } else { "Epiphany" }, "White");
+    }
+
+    // From Epiphany to Lent
+    if abs < ash_wednesday {
+        let last_sunday = ash_wednesday - 3;
+        return match tradition {
+            RomanCatholic if abs < baptism => day(format!("{} after Epiphany", WEEKDAYS[weekday as usize]), "Christmas", "White"),
+            RomanCatholic if abs == baptism => day("Baptism of the Lord", "Christmas", "White"),
+            RomanCatholic => day(numbered_week(abs, (sunday - baptism) / 7 + 1, "in Ordinary Time"), "Ordinary Time", "Green"),
+            Lutheran if abs == ash_wednesday - 1 => day("Shrove Tuesday", "Time after Epiphany", "Green"),
+            _ if abs == ash_wednesday - 1 => day("Shrove Tuesday", "Ordinary Time", "Green"),
+            Lutheran => {
+                let title = match (sunday - baptism) / 7 + 1 {
+                    _ if sunday == last_sunday => "Transfiguration of Our Lord".to_string(),
+                    0 => "Epiphany of Our Lord".to_string(),
+                    1 => "Baptism of Our Lord".to_string(),
+                    n => format!("{} Sunday after Epiphany", ordinal(n)),
+                };
+                let colour = if weekday == 0 && (sunday == baptism || sunday == last_sunday) { "White" } else { "Green" };
+                day(week_of_sunday(abs, title), "Time after Epiphany", colour)
+            }
+            _ if abs <= ymd(year, 2, 2) => {
+                let title = match (sunday - baptism) / 7 + 1 {
+                    1 => "Baptism of Christ".to_string(),
+                    n if n < 1 => "Epiphany".to_string(),
+                    n => format!("{} Sunday of Epiphany", ordinal(n)),
+                };
+                day(week_of_sunday(abs, title), "Epiphany", "White")
+            }
+            _ => {
+                let title = match (last_sunday - sunday) / 7 + 1 {
+                    1 => "Sunday next before Lent".to_string(),
+                    n => format!("{} Sunday before Lent", ordinal(n)),
+                };
+                day(week_of_sunday(abs, title), "Ordinary Time", "Green")
+            }
+        };
+    }
+
+    // Lent and Holy Week
+    if abs < easter {
+        let roman = tradition == RomanCatholic;
+        return match easter - abs {
+            46 => day("Ash Wednesday", "Lent", purple),
+            43..=45 => day(format!("{} after Ash Wednesday", WEEKDAYS[weekday as usize]), "Lent", purple),
+            7 if roman => day("Palm Sunday of the Passion of the Lord", "Holy Week", holy_week),
+            7 => day("Palm Sunday", "Holy Week", holy_week),
+            4..=6 => day(format!("{} of Holy Week", WEEKDAYS[weekday as usize]), "Holy Week", if roman { purple } else { holy_week }),
+            3 if roman => day("Holy Thursday", "Paschal Triduum", "White"),
+            3 => day("Maundy Thursday", "Holy Week", "White"),
+            2 => day("Good Friday", if roman { "Paschal Triduum" } else { "Holy Week" }, if tradition == Lutheran { "None" } else { "Red" }),
+            1 => day("Holy Saturday", if roman { "Paschal Triduum" } else { "Holy Week" }, "None"),
+            _ => {
+                let week = (sunday - (ash_wednesday + 4)) / 7 + 1;
+                let colour = if roman && week == 4 && weekday == 0 { "Rose" } else { purple };
+                day(numbered_week(abs, week, "of Lent"), "Lent", colour)
+            }
+        };
+    }
+
+    // Eastertide
+    if abs <= pentecost {
+        return match abs - easter {
+            0 => day("Easter Sunday", "Easter", "White"),
+            1..=6 if tradition == RomanCatholic => {
+                day(format!("{} within the Octave of Easter", WEEKDAYS[weekday as usize]), "Easter", "White")
+            }
+            1..=6 => day(format!("Easter {}", WEEKDAYS[weekday as usize]), "Easter", "White"),
+            39 => day("Ascension of the Lord", "Easter", "White"),
+            49 => day("Pentecost Sunday", "Easter", "Red"),
+            _ => day(numbered_week(abs, (sunday - easter) / 7 + 1, "of Easter"), "Easter", "White"),
+        };
+    }
+
+    // From Pentecost to Advent; the last Sunday is Christ the King in every Western tradition
+    let trinity = pentecost + 7;
+    let season = if tradition == Lutheran { "Time after Pentecost" } else { "Ordinary Time" };
+    if abs == trinity {
+        let title = match tradition {
+            RomanCatholic => "The Most Holy Trinity",
+            Anglican => "Trinity Sunday",
+            _ => "Holy Trinity",
+        };
+        return day(title, season, "White");
+    }
+    if abs == advent - 7 {
+        return day("Christ the King", season, "White");
+    }
+    match tradition {
+        RomanCatholic if abs == trinity + 4 => day("The Most Holy Body and Blood of Christ", "Ordinary Time", "White"),
+        RomanCatholic => day(numbered_week(abs, 35 - (advent - sunday) / 7, "in Ordinary Time"), "Ordinary Time", "Green"),
+        Anglican => {
+            let title = match (advent - sunday) / 7 {
+                weeks @ 2..=4 => format!("{} Sunday before Advent", ordinal(weeks)),
+                1 => "Christ the King".to_string(),
+                _ if sunday == pentecost => "Pentecost Sunday".to_string(),
+                _ if sunday == trinity => "Trinity Sunday".to_string(),
+                _ => format!("{} Sunday after Trinity", ordinal((sunday - trinity) / 7)),
+            };
+            day(week_of_sunday(abs, title), "Ordinary Time", "Green")
+        }
+        _ => {
+            let title = match (sunday - pentecost) / 7 {
+                _ if sunday == advent - 7 => "Christ the King".to_string(),
+                0 => "Pentecost Sunday".to_string(),
+                1 => "Holy Trinity".to_string(),
+                n => format!("{} Sunday after Pentecost", ordinal(n)),
+            };
+            day(week_of_sunday(abs, title), season, "Green")
+        }
+    }
+}
+
+fn january_first(tradition: Tradition) -> &'static str {
+    match tradition {
+        RomanCatholic => "Solemnity of Mary, Mother of God",
+        Anglican => "Naming and Circumcision of Jesus",
+        _ => "Name of Jesus",
+    }
+}
+
+// Principal fixed feasts that are not already named by the season
+fn western_feast(date: NaiveDate, tradition: Tradition) -> Option<&'static str> {
+    let roman = tradition == RomanCatholic;
+    match (date.month(), date.day()) {
+        (1, 25) => Some("Conversion of St Paul"),
+        (2, 2) => Some("Presentation of the Lord"),
+        (3, 19) => Some(if roman { "St Joseph, Spouse of the Blessed Virgin Mary" } else { "Joseph of Nazareth" }),
+        (3, 25) => Some("Annunciation of the Lord"),
+        (6, 24) => Some("Nativity of St John the Baptist"),
+        (6, 29) => Some("Sts Peter and Paul, Apostles"),
+        (8, 6) if tradition != Lutheran => Some("Transfiguration of the Lord"),
+        (8, 15) => Some(match tradition {
+            RomanCatholic => "Assumption of the Blessed Virgin Mary",
+            Anglican => "Blessed Virgin Mary",
+            _ => "Mary, Mother of Our Lord",
+        }),
+        (9, 14) => Some(if roman { "Exaltation of the Holy Cross" } else { "Holy Cross Day" }),
+        (10, 31) if tradition == Lutheran => Some("Reformation Day"),
+        (11, 1) => Some("All Saints"),
+        (11, 2) if tradition != Lutheran => Some(if roman { "All Souls" } else { "Commemoration of the Faithful Departed" }),
+        (12, 8) if roman => Some("Immaculate Conception of the Blessed Virgin Mary"),
+        (12, 26) => Some("St Stephen, First Martyr"),
+        _ => None,
+    }
+}
+
+/* -------------------------
+    Eastern Orthodox
+    ------------------------- */
+
+// Great feasts and other major days on the Julian (Old Style) calendar, with their vestment colours
+const ORTHODOX_FEASTS: [(u32, u32, &str, &str); 12] = [
+    (1, 1, "Circumcision of the Lord · St Basil the Great", "White"),
+    (1, 6, "Theophany of the Lord", "White"),
+    (2, 2, "Meeting of the Lord in the Temple", "Blue"),
+    (3, 25, "Annunciation of the Most Holy Theotokos", "Blue"),
+    (6, 29, "Holy Apostles Peter and Paul", "Red"),
+    (8, 6, "Transfiguration of the Lord", "White"),
+    (8, 15, "Dormition of the Most Holy Theotokos", "Blue"),
+    (8, 29, "Beheading of St John the Baptist", "Red"),
+    (9, 8, "Nativity of the Most Holy Theotokos", "Blue"),
+    (9, 14, "Exaltation of the Precious Cross", "Purple"),
+    (11, 21, "Entry of the Most Holy Theotokos into the Temple", "Blue"),
+    (12, 25, "Nativity of Christ", "White"),
+];
+
+fn orthodox_day(abs: i64) -> LiturgicalDay {
+    let weekday = abs.rem_euclid(7);
+    let weekday_name = WEEKDAYS[weekday as usize];
+    let sunday = sunday_on_or_before(abs);
+    let (julian_year, julian_month, julian_day) = julian_from_absolute(abs);
+    let pascha = absolute(julian_easter(from_absolute(abs).year()));
+
+    let moveable = match abs - pascha {
+        -70 => Some(day("Sunday of the Publican and the Pharisee", "Triodion", "Gold")),
+        -63 => Some(day("Sunday of the Prodigal Son", "Triodion", "Gold")),
+        -56 => Some(day("Sunday of the Last Judgment (Meatfare)", "Triodion", "Gold")),
+        -49 => Some(day("Sunday of Forgiveness (Cheesefare)", "Triodion", "Gold")),
+        n @ -69..=-50 => Some(day(format!("{} of the {} Week of the Triodion", weekday_name, ordinal((n + 70) / 7 + 1)), "Triodion", "Gold")),
+        -48 => Some(day("Clean Monday", "Great Lent", "Dark purple")),
+        -42 => Some(day("Sunday of Orthodoxy", "Great Lent", "Purple")),
+        -35 => Some(day("Sunday of St Gregory Palamas", "Great Lent", "Purple")),
+        -28 => Some(day("Sunday of the Veneration of the Cross", "Great Lent", "Purple")),
+        -21 => Some(day("Sunday of St John Climacus", "Great Lent", "Purple")),
+        -14 => Some(day("Sunday of St Mary of Egypt", "Great Lent", "Purple")),
+        -8 => Some(day("Lazarus Saturday", "Great Lent", "Green")),
+        n @ -47..=-9 => Some(day(format!("{} of the {} Week of Great Lent", weekday_name, ordinal((n + 48) / 7 + 1)), "Great Lent", "Dark purple")),
+        -7 => Some(day("Entry of the Lord into Jerusalem (Palm Sunday)", "Holy Week", "Green")),
+        -3 => Some(day("Great and Holy Thursday", "Holy Week", "Dark red")),
+        -2..=-1 => Some(day(format!("Great and Holy {}", weekday_name), "Holy Week", "Black")),
+        -6..=-4 => Some(day(format!("Great and Holy {}", weekday_name), "Holy Week", "Dark purple")),
+        0 => Some(day("Pascha: the Resurrection of the Lord", "Bright Week", "Red")),
+        1..=6 => Some(day(format!("Bright {}", weekday_name), "Bright Week", "Red")),
+        7 => Some(day("Thomas Sunday", "Pentecostarion", "Red")),
+        14 => Some(day("Sunday of the Myrrh-bearing Women", "Pentecostarion", "Red")),
+        21 => Some(day("Sunday of the Paralytic", "Pentecostarion", "Red")),
+        24 => Some(day("Mid-Pentecost", "Pentecostarion", "Red")),
+        28 => Some(day("Sunday of the Samaritan Woman", "Pentecostarion", "Red")),
+        35 => Some(day("Sunday of the Blind Man", "Pentecostarion", "Red")),
+        39 => Some(day("Ascension of the Lord", "Pentecostarion", "White")),
+        42 => Some(day("Sunday of the Holy Fathers of the First Ecumenical Council", "Pentecostarion", "Red")),
+        49 => Some(day("Pentecost: Holy Trinity", "Pentecostarion", "Green")),
+        50 => Some(day("Monday of the Holy Spirit", "Pentecostarion", "Green")),
+        56 => Some(day("Sunday of All Saints", "Pentecostarion", "Gold")),
+        // Trinity Week, kept without fasting
+        51..=55 => Some(day(format!("{} of the Week after Pentecost", weekday_name), "Pentecostarion (fast-free)", "Green")),
+        n @ 8..=55 => Some(day(format!("{} of the {} Week of Pascha", weekday_name, ordinal(n / 7 + 1)), "Pentecostarion", "Red")),
+        _ => None,
+    };
+    if let Some(moveable) = moveable {
+        return moveable;
+    }
+
+    // Sundays after Pentecost run on until the next Triodion
+    let pentecost = if abs < pascha { absolute(julian_easter(from_absolute(abs).year() - 1)) + 49 } else { pascha + 49 };
+    let week = (sunday - pentecost) / 7;
+    let title = if weekday == 0 {
+        format!("{} Sunday after Pentecost", ordinal(week))
+    } else {
+        format!("{} of the {} Week after Pentecost", weekday_name, ordinal(week + 1))
+    };
+
+    let apostles_fast_ends = absolute_from_julian(julian_year, 6, 28);
+    let season = match (julian_month, julian_day) {
+        (8, 1..=14) => "Dormition Fast",
+        (11, 15..=30) | (12, 1..=24) => "Nativity Fast",
+        (12, 25..=31) | (1, 1..=5) => "Christmastide",
+        _ if abs > pascha + 56 && abs <= apostles_fast_ends => "Apostles' Fast",
+        _ => "Time after Pentecost",
+    };
+    day(title, season, "Gold")
+}
+
+fn orthodox_feast(abs: i64) -> Option<(&'static str, &'static str)> {
+    let (_, month, day) = julian_from_absolute(abs);
+    ORTHODOX_FEASTS
+        .iter()
+        .find(|(m, d, _, _)| (*m, *d) == (month, day))
+        .map(|&(_, _, name, colour)| (name, colour))
+}
+
+impl super::Calendar for LiturgicalCalendar {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&UserSettings>) -> CalendarDate {
+        let naive = date.naive_local().date();
+        let abs = absolute(naive);
+        let tradition = Tradition::from_settings(settings);
+        let easter = gregorian_easter(naive.year());
+        let pascha = julian_easter(naive.year());
+
+        let mut info = Vec::new();
+        let liturgical = if tradition == Orthodox {
+            let mut liturgical = orthodox_day(abs);
+            if let Some((feast, colour)) = orthodox_feast(abs) {
+                info.push(feast.to_string());
+                liturgical.colour = colour;
+            }
+            liturgical
+        } else {
+            let liturgical = western_day(abs, tradition);
+            info.extend(western_feast(naive, tradition).map(str::to_string));
+            liturgical
+        };
+        info.push(format!("{} · {}", liturgical.season, liturgical.colour));
+
+        if tradition == Orthodox {
+            let (_, month, day) = julian_from_absolute(abs);
+            info.push(format!("{} {} (Old Style)", day, month_name(month)));
+            info.push(format!("Pascha {}", format_date(pascha)));
+            info.push(format!("Western Easter {}", format_date(easter)));
+        } else {
+            info.push(format!("Easter {}", format_date(easter)));
+            info.push(format!("Orthodox Pascha {}", format_date(pascha)));
+        }
+
+        CalendarDate {
+            system: format!("Liturgical ({})", tradition.name()),
+            date: liturgical.title,
+            additional_info: Some(info.join(" · ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn computes_easter() {
+        for (year, month, day) in [(1818, 3, 22), (2000, 4, 23), (2019, 4, 21), (2024, 3, 31), (2026, 4, 5), (2038, 4, 25)] {
+            assert_eq!(gregorian_easter(year), date(year, month, day));
+        }
+        for (year, month, day) in [(2023, 4, 16), (2024, 5, 5), (2025, 4, 20), (2026, 4, 12)] {
+            assert_eq!(julian_easter(year), date(year, month, day));
+        }
+    }
+
+    #[test]
+    fn names_western_days() {
+        let cases = [
+            ((2026, 2, 18), RomanCatholic, "Ash Wednesday"),
+            ((2026, 3, 15), RomanCatholic, "4th Sunday of Lent"),
+            ((2026, 6, 4), RomanCatholic, "The Most Holy Body and Blood of Christ"),
+            ((2026, 10, 18), RomanCatholic, "29th Sunday in Ordinary Time"),
+            ((2026, 10, 18), Anglican, "20th Sunday after Trinity"),
+            ((2026, 11, 22), Lutheran, "Christ the King"),
+            ((2026, 11, 29), RomanCatholic, "1st Sunday of Advent"),
+        ];
+        for ((year, month, day), tradition, expected) in cases {
+            assert_eq!(western_day(absolute(date(year, month, day)), tradition).title, expected);
+        }
+    }
+
+    #[test]
+    fn names_orthodox_days() {
+        let cases = [
+            ((2026, 2, 23), "Clean Monday"),
+            ((2026, 4, 12), "Pascha: the Resurrection of the Lord"),
+            ((2026, 5, 31), "Pentecost: Holy Trinity"),
+            ((2026, 6, 3), "Wednesday of the Week after Pentecost"),
+            ((2026, 6, 7), "Sunday of All Saints"),
+        ];
+        for ((year, month, day), expected) in cases {
+            assert_eq!(orthodox_day(absolute(date(year, month, day))).title, expected);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+// microseasons.rs
+// Japanese 24 sekki and 72 kō (shichijūni kō), after the Ryakuhon-reki almanac of 1874, reckoned by
+// the sun's ecliptic longitude from Risshun at 315°
+
+use super::local_time;
+use crate::astronomy::seasons::solar_longitude_instant;
+use crate::astronomy::sun::solar_position;
+use crate::astronomy::julian_day;
+use crate::models::CalendarDate;
+use chrono::{DateTime, Duration, Local, Utc};
+
+pub struct MicroseasonsCalendar;
+
+// Romanized name, kanji, English
+type Name = (&'static str, &'static str, &'static str);
+
+const SEKKI: [Name; 24] = [
+    ("Risshun", "立春", "Beginning of spring"),
+    ("Usui", "雨水", "Rainwater"),
+    ("Keichitsu", "啓蟄", "Insects awaken"),
+    ("Shunbun", "春分", "Spring equinox"),
+    ("Seimei", "清明", "Pure and clear"),
+    ("Kokuu", "穀雨", "Grain rain"),
+    ("Rikka", "立夏", "Beginning of summer"),
+    ("Shōman", "小満", "Lesser ripening"),
+    ("Bōshu", "芒種", "Grain beards and seeds"),
+    ("Geshi", "夏至", "Summer solstice"),
+    ("Shōsho", "小暑", "Lesser heat"),
+    ("Taisho", "大暑", "Greater heat"),
+    ("Risshū", "立秋", "Beginning of autumn"),
+    ("Shosho", "処暑", "Manageable heat"),
+    ("Hakuro", "白露", "White dew"),
+    ("Shūbun", "秋分", "Autumn equinox"),
+    ("Kanro", "寒露", "Cold dew"),
+    ("Sōkō", "霜降", "Frost falls"),
+    ("Rittō", "立冬", "Beginning of winter"),
+    ("Shōsetsu", "小雪", "Lesser snow"),
+    ("Taisetsu", "大雪", "Greater snow"),
+    ("Tōji", "冬至", "Winter solstice"),
+    ("Shōkan", "小寒", "Lesser cold"),
+    ("Daikan", "大寒", "Greater cold"),
+];
+
+const KO: [Name; 72] = [
+    ("Harukaze kōri o toku", "東風解凍", "East wind melts the ice"),
+    ("Kōō kenkan su", "黄鶯睍睆", "Bush warblers start singing in the mountains"),
+    ("Uo kōri o izuru", "魚上氷", "Fish emerge from the ice"),
+    ("Tsuchi no shō uruoi okoru", "土脉潤起", "Rain moistens the soil"),
+    ("Kasumi hajimete tanabiku", "霞始靆", "Mist starts to linger"),
+    ("Sōmoku mebae izuru", "草木萌動", "Grass sprouts, trees bud"),
+    ("Sugomori mushito o hiraku", "蟄虫啓戸", "Hibernating insects surface"),
+    ("Momo hajimete saku", "桃始笑", "First peach blossoms"),
+    ("Namushi chō to naru", "菜虫化蝶", "Caterpillars become butterflies"),
+    ("Suzume hajimete sukuu", "雀始巣", "Sparrows start to nest"),
+    ("Sakura hajimete saku", "櫻始開", "First cherry blossoms"),
+    ("Kaminari sunawachi koe o hassu", "雷乃発声", "Distant thunder"),
+    ("Tsubame kitaru", "玄鳥至", "Swallows return"),
+    ("Kōgan kaeru", "鴻雁北", "Wild geese fly north"),
+    ("Niji hajimete arawaru", "虹始見", "First rainbows"),
+    ("Ashi hajimete shōzu", "葭始生", "First reeds sprout"),
+    ("Shimo yamite nae izuru", "霜止出苗", "Last frost, rice seedlings grow"),
+    ("Botan hana saku", "牡丹華", "Peonies bloom"),
+    ("Kawazu hajimete naku", "蛙始鳴", "Frogs start singing"),
+    ("Mimizu izuru", "蚯蚓出", "Worms surface"),
+    ("Takenoko shōzu", "竹笋生", "Bamboo shoots sprout"),
+    ("Kaiko okite kuwa o hamu", "蚕起食桑", "Silkworms start feasting on mulberry leaves"),
+    ("Benibana sakau", "紅花栄", "Safflowers bloom"),
+    ("Mugi no toki itaru", "麦秋至", "Wheat ripens and is harvested"),
+    ("Kamakiri shōzu", "蟷螂生", "Praying mantises hatch"),
+    ("Kusaretaru kusa hotaru to naru", "腐草為螢", "Rotten grass becomes fireflies"),
+    ("Ume no mi kibamu", "梅子黄", "Plums turn yellow"),
+    ("Natsukarekusa karuru", "乃東枯", "Self-heal withers"),
+    ("Ayame hana saku", "菖蒲華", "Irises bloom"),
+    ("Hange shōzu", "半夏生", "Crow-dipper sprouts"),
+    ("Atsukaze itaru", "温風至", "Warm winds blow"),
+    ("Hasu hajimete hiraku", "蓮始開", "First lotus blossoms"),
+    ("Taka sunawachi waza o narau", "鷹乃学習", "Hawks learn to fly"),
+    ("Kiri hajimete hana o musubu", "桐始結花", "Paulownia trees produce seeds"),
+    ("Tsuchi uruōte mushi atsushi", "土潤溽暑", "Earth is damp, air is humid"),
+    ("Taiu tokidoki furu", "大雨時行", "Great rains sometimes fall"),
+    ("Suzukaze itaru", "涼風至", "Cool winds blow"),
+    ("Higurashi naku", "寒蝉鳴", "Evening cicadas sing"),
+    ("Fukaki kiri matō", "蒙霧升降", "Thick fog descends"),
+    ("Wata no hana shibe hiraku", "綿柎開", "Cotton flowers bloom"),
+    ("Tenchi hajimete samushi", "天地始粛", "Heat starts to die down"),
+    ("Kokumono sunawachi minoru", "禾乃登", "Rice ripens"),
+    ("Kusa no tsuyu shiroshi", "草露白", "Dew glistens white on grass"),
+    ("Sekirei naku", "鶺鴒鳴", "Wagtails sing"),
+    ("Tsubame saru", "玄鳥去", "Swallows leave"),
+    ("Kaminari sunawachi koe o osamu", "雷乃収声", "Thunder ceases"),
+    ("Mushi kakurete to o fusagu", "蟄虫坏戸", "Insects hole up underground"),
+    ("Mizu hajimete karuru", "水始涸", "Farmers drain fields"),
+    ("Kōgan kitaru", "鴻雁来", "Wild geese return"),
+    ("Kiku no hana hiraku", "菊花開", "Chrysanthemums bloom"),
+    ("Kirigirisu to ni ari", "蟋蟀在戸", "Crickets chirp around the door"),
+    ("Shimo hajimete furu", "霜始降", "First frost"),
+    ("Kosame tokidoki furu", "霎時施", "Light rains sometimes fall"),
+    ("Momiji tsuta kibamu", "楓蔦黄", "Maple leaves and ivy turn yellow"),
+    ("Tsubaki hajimete hiraku", "山茶始開", "Camellias bloom"),
+    ("Chi hajimete kōru", "地始凍", "Land starts to freeze"),
+    ("Kinsenka saku", "金盞香", "Daffodils bloom"),
+    ("Niji kakurete miezu", "虹蔵不見", "Rainbows hide"),
+    ("Kitakaze konoha o harau", "朔風払葉", "North wind blows the leaves from the trees"),
+    ("Tachibana hajimete kibamu", "橘始黄", "Tachibana citrus leaves start to turn yellow"),
+    ("Sora samuku fuyu to naru", "閉塞成冬", "Cold sets in, winter begins"),
+    ("Kuma ana ni komoru", "熊蟄穴", "Bears start hibernating in their dens"),
+    ("Sake no uo muragaru", "鱖魚群", "Salmon gather and swim upstream"),
+    ("Natsukarekusa shōzu", "乃東生", "Self-heal sprouts"),
+    ("Sawashika no tsuno otsuru", "麋角解", "Deer shed antlers"),
+    ("Yuki watarite mugi nobiru", "雪下出麦", "Wheat sprouts under snow"),
+    ("Seri sunawachi sakau", "芹乃栄", "Parsley flourishes"),
+    ("Shimizu atataka o fukumu", "水泉動", "Springs thaw"),
+    ("Kiji hajimete naku", "雉始雊", "Pheasants start to call"),
+    ("Fuki no hana saku", "款冬華", "Butterburs bud"),
+    ("Sawamizu kōri tsumeru", "水沢腹堅", "Ice thickens on streams"),
+    ("Niwatori hajimete toya ni tsuku", "鶏始乳", "Hens start laying eggs"),
+];
+
+// Sun's longitude at Risshun, where the first kō begins
+const RISSHUN_LONGITUDE: f64 = 315.0;
+const KO_DEGREES: f64 = 5.0;
+
+// Index of the kō, 0 to 71, in force at `instant`
+fn ko_at(instant: DateTime<Utc>) -> usize {
+    let longitude = solar_position(julian_day(instant)).longitude;
+    ((longitude - RISSHUN_LONGITUDE).rem_euclid(360.0) / KO_DEGREES) as usize % KO.len()
+}
+
+impl super::Calendar for MicroseasonsCalendar {
+    fn convert(&self, date: &DateTime<Local>, _settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        // As in the almanac, a kō begins on the civil day during which the sun reaches its longitude
+        let today = date.date_naive();
+        let end_of_day = local_time(today + Duration::days(1), 0) - Duration::seconds(1);
+        let ko = ko_at(end_of_day);
+        let sekki = ko / 3;
+
+        let next = (ko + 1) % KO.len();
+        let next_longitude = RISSHUN_LONGITUDE + next as f64 * KO_DEGREES;
+        let next_start = solar_longitude_instant(next_longitude, end_of_day + Duration::days(3));
+
+        let (romaji, kanji, english) = KO[ko];
+        let (sekki_romaji, sekki_kanji, sekki_english) = SEKKI[sekki];
+        let info = [
+            kanji.to_string(),
+            format!("Kō {} of 72", ko + 1),
+            format!("{} {} ({}), part {} of 3", sekki_romaji, sekki_kanji, sekki_english, ko % 3 + 1),
+            format!(
+                "Next: {} — {}, {}",
+                KO[next].0,
+                KO[next].2,
+                next_start.with_timezone(&Local).format("%a %-d %b")
+            ),
+        ];
+
+        CalendarDate {
+            system: "Japanese Microseasons".to_string(),
+            date: format!("{} — {}", romaji, english),
+            additional_info: Some(info.join(" · ")),
+        }
+    }
+}
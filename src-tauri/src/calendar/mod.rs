@@ -3,13 +3,59 @@
 
 pub mod gregorian;
 pub mod julian;
+pub mod historical;
+pub mod roman;
+pub mod byzantine;
+pub mod icelandic;
 pub mod buddhist;
+pub mod thai_lunar;
 pub mod french_revolutionary;
 pub mod jewish;
+pub mod liturgical;
+pub mod chronology;
+pub mod moon;
+pub mod seasons;
+pub mod microseasons;
+pub mod zodiac;
+pub mod sky;
+pub mod anniversaries;
+pub mod learning;
+pub mod zmanim;
+pub mod prayer_times;
+pub mod sun_times;
 
-use crate::models::CalendarDate;
-use chrono::{DateTime, Local};
+use crate::models::{CalendarDate, UserSettings};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
 
 pub trait Calendar {
     fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate;
-}
\ No newline at end of file
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+// English month name for a 1-based month number (shared by the Julian-style calendars)
+pub fn month_name(month: u32) -> &'static str {
+    MONTH_NAMES.get(month.wrapping_sub(1) as usize).copied().unwrap_or("")
+}
+
+// The instant the clock reads `hour`:00 on a local date; an hour skipped by a clock change is taken as UTC
+pub fn local_time(date: NaiveDate, hour: u32) -> DateTime<Utc> {
+    let naive = date.and_hms_opt(hour, 0, 0).unwrap();
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&naive))
+}
+
+// Local clock time of an instant, 24-hour or 12-hour as "date_format" asks (24-hour without settings)
+pub fn format_time(time: DateTime<Utc>, settings: Option<&UserSettings>) -> String {
+    let local = time.with_timezone(&Local);
+    match settings {
+        Some(settings) => zmanim::format_zman(local, settings),
+        None => local.format("%H:%M").to_string(),
+    }
+}
@@ -0,0 +1,138 @@
+// moon.rs
+// Phase of the moon, its illumination and age, the coming principal phases, moonrise and moonset,
+// and the traditional name of each full moon
+
+use super::jewish::{hebrew_from_gregorian, hebrew_month_name};
+use super::{format_time, local_time};
+use crate::astronomy::julian_day;
+use crate::astronomy::moon::{elongation, illuminated_fraction, next_phase, previous_phase, rise_and_set, Phase};
+use crate::astronomy::seasons::september_equinox;
+use crate::models::{CalendarDate, UserSettings};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc};
+
+pub struct MoonCalendar;
+
+// Names in the Old Farmer's Almanac, by the month of the full moon
+const NORTH_AMERICAN_NAMES: [&str; 12] = [
+    "Wolf Moon", "Snow Moon", "Worm Moon", "Pink Moon", "Flower Moon", "Strawberry Moon",
+    "Buck Moon", "Sturgeon Moon", "Corn Moon", "Hunter's Moon", "Beaver Moon", "Cold Moon",
+];
+
+// Bede's Old English months, which were reckoned by the moon
+const OLD_ENGLISH_MONTHS: [&str; 12] = [
+    "Æfterra Gēola", "Solmōnaþ", "Hrēþmōnaþ", "Ēosturmōnaþ", "Þrimilcemōnaþ", "Ærra Līþa",
+    "Æfterra Līþa", "Weodmōnaþ", "Hāligmōnaþ", "Winterfylleþ", "Blōtmōnaþ", "Ærra Gēola",
+];
+
+fn local_date(time: DateTime<Utc>) -> NaiveDate {
+    time.with_timezone(&Local).date_naive()
+}
+
+// The Harvest Moon is the full moon nearest the September equinox, and the Hunter's Moon the one after it;
+// a second full moon in one calendar month is a Blue Moon
+fn north_american_name(full: DateTime<Utc>) -> String {
+    let equinox = september_equinox(local_date(full).year());
+    let (before, after) = (previous_phase(equinox, Phase::Full), next_phase(equinox, Phase::Full));
+    let harvest = if equinox - before < after - equinox { before } else { after };
+
+    if full == harvest {
+        return "Harvest Moon".to_string();
+    }
+    if full == next_phase(harvest, Phase::Full) {
+        return "Hunter's Moon".to_string();
+    }
+
+    let date = local_date(full);
+    let previous = local_date(previous_phase(full - Duration::minutes(1), Phase::Full));
+    if (previous.year(), previous.month()) == (date.year(), date.month()) {
+        return "Blue Moon".to_string();
+    }
+    // With the Harvest Moon in October, September's full moon keeps its own name
+    NORTH_AMERICAN_NAMES[date.month0() as usize].to_string()
+}
+
+// Traditional name of the full moon at `full` under the "moon_names" setting
+pub fn full_moon_name(full: DateTime<Utc>, settings: Option<&UserSettings>) -> Option<String> {
+    let tradition = settings.and_then(|s| s.moon_names.as_deref()).unwrap_or("north_american");
+    let date = local_date(full);
+    match tradition {
+        "none" => None,
+        "old_english" => Some(format!("{} Moon", OLD_ENGLISH_MONTHS[date.month0() as usize])),
+        "hebrew" => {
+            let hebrew = hebrew_from_gregorian(date);
+            Some(hebrew_month_name(hebrew.year, hebrew.month).0.to_string())
+        }
+        _ => Some(north_american_name(full)),
+    }
+}
+
+// Name of the phase between the principal ones, from the moon's elongation from the sun
+fn intermediate_phase(elongation: f64) -> &'static str {
+    match elongation {
+        e if e < 90.0 => "Waxing Crescent",
+        e if e < 180.0 => "Waxing Gibbous",
+        e if e < 270.0 => "Waning Gibbous",
+        _ => "Waning Crescent",
+    }
+}
+
+impl super::Calendar for MoonCalendar {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let now = date.with_timezone(&Utc);
+        let today = date.date_naive();
+        let jd = julian_day(now);
+        let with_name = |phase: Phase, instant: DateTime<Utc>| match phase {
+            Phase::Full => match full_moon_name(instant, settings) {
+                Some(name) => format!("{} ({})", phase.name(), name),
+                None => phase.name().to_string(),
+            },
+            _ => phase.name().to_string(),
+        };
+
+        // A principal phase names the whole civil day on which it falls
+        let start = local_time(today, 0);
+        let end = local_time(today + Duration::days(1), 0);
+        let phase_today = Phase::ALL
+            .iter()
+            .map(|&phase| (phase, next_phase(start - Duration::seconds(1), phase)))
+            .find(|(_, instant)| *instant < end);
+        let phase = match phase_today {
+            Some((phase, instant)) => format!("{} at {}", with_name(phase, instant), format_time(instant, settings)),
+            None => intermediate_phase(elongation(jd)).to_string(),
+        };
+        let illumination = (illuminated_fraction(jd) * 100.0).round();
+
+        let age = (now - previous_phase(now, Phase::New)).num_minutes() as f64 / 1440.0;
+        let mut info = vec![format!("Age {:.1} days", age)];
+
+        let mut upcoming: Vec<(Phase, DateTime<Utc>)> =
+            Phase::ALL.iter().map(|&phase| (phase, next_phase(now, phase))).collect();
+        upcoming.sort_by_key(|(_, instant)| *instant);
+        info.extend(upcoming.into_iter().map(|(phase, instant)| {
+            format!(
+                "{} {} {}",
+                with_name(phase, instant),
+                instant.with_timezone(&Local).format("%a %-d %b"),
+                format_time(instant, settings)
+            )
+        }));
+
+        if let Some((latitude, longitude)) = settings.and_then(|s| s.latitude.zip(s.longitude)) {
+            let (rise, set) = rise_and_set(start, end, latitude, longitude);
+            info.push(match rise {
+                Some(rise) => format!("Moonrise {}", format_time(rise, settings)),
+                None => "No moonrise today".to_string(),
+            });
+            info.push(match set {
+                Some(set) => format!("Moonset {}", format_time(set, settings)),
+                None => "No moonset today".to_string(),
+            });
+        }
+
+        CalendarDate {
+            system: "Moon".to_string(),
+            date: format!("{} · {}% illuminated", phase, illumination),
+            additional_info: Some(info.join(" · ")),
+        }
+    }
+}
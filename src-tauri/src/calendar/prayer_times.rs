@@ -0,0 +1,148 @@
+// prayer_times.rs
+// Islamic prayer times for the configured location, by the usual calculation methods (after PrayTimes.org)
+
+use crate::astronomy::julian_day;
+use crate::astronomy::sun::{horizon_altitude, solar_noon, solar_position, time_at_altitude};
+use crate::models::{PrayerTimes, UserSettings};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+
+// How Isha is reckoned: by the sun's depression, or a fixed interval after Maghrib
+#[derive(Clone, Copy)]
+enum Isha {
+    Degrees(f64),
+    Minutes(i64),
+}
+
+#[derive(Clone, Copy)]
+struct Method {
+    key: &'static str,
+    name: &'static str,
+    fajr_degrees: f64,
+    isha: Isha,
+    // Depression of the sun for Maghrib, where a method waits past sunset
+    maghrib_degrees: Option<f64>,
+}
+
+const METHODS: [Method; 6] = [
+    Method { key: "mwl", name: "Muslim World League", fajr_degrees: 18.0, isha: Isha::Degrees(17.0), maghrib_degrees: None },
+    Method { key: "isna", name: "ISNA", fajr_degrees: 15.0, isha: Isha::Degrees(15.0), maghrib_degrees: None },
+    Method { key: "egypt", name: "Egyptian General Authority of Survey", fajr_degrees: 19.5, isha: Isha::Degrees(17.5), maghrib_degrees: None },
+    Method { key: "umm_al_qura", name: "Umm al-Qura, Makkah", fajr_degrees: 18.5, isha: Isha::Minutes(90), maghrib_degrees: None },
+    Method { key: "karachi", name: "University of Islamic Sciences, Karachi", fajr_degrees: 18.0, isha: Isha::Degrees(18.0), maghrib_degrees: None },
+    Method { key: "tehran", name: "Institute of Geophysics, Tehran", fajr_degrees: 17.7, isha: Isha::Degrees(14.0), maghrib_degrees: Some(4.5) },
+];
+
+// Minutes before Fajr at which the pre-dawn meal ends
+const IMSAK_MINUTES: i64 = 10;
+
+fn resolve_method(key: Option<&str>) -> Method {
+    let key = key.unwrap_or("mwl").trim().to_lowercase();
+    METHODS.iter().find(|m| m.key == key).copied().unwrap_or(METHODS[0])
+}
+
+// Altitude of the sun when an object's shadow is its noon shadow plus `factor` times its height
+// (1 for the Shafi'i, Maliki and Hanbali schools, 2 for the Hanafi)
+fn asr_altitude(date: NaiveDate, latitude: f64, longitude: f64, factor: f64) -> f64 {
+    let declination = solar_position(julian_day(solar_noon(date, longitude))).declination;
+    (1.0 / (factor + (latitude - declination).abs().to_radians().tan())).atan().to_degrees()
+}
+
+// Where the twilight angles are never reached, or fall absurdly far into the night, Fajr and Isha are
+// held to a portion of the night: half of it, a seventh, or the twilight angle over sixty
+fn night_portion(rule: &str, degrees: f64) -> Option<f64> {
+    match rule {
+        "none" => None,
+        "middle_of_night" => Some(0.5),
+        "one_seventh" => Some(1.0 / 7.0),
+        _ => Some(degrees / 60.0),
+    }
+}
+
+pub fn prayer_times(date: NaiveDate, settings: &UserSettings) -> Result<PrayerTimes, String> {
+    let (latitude, longitude) = match (settings.latitude, settings.longitude) {
+        (Some(latitude), Some(longitude)) => (latitude, longitude),
+        _ => return Err("Set latitude and longitude in settings.json to compute prayer times".to_string()),
+    };
+    let method = resolve_method(settings.prayer_method.as_deref());
+    let asr_factor = if settings.prayer_asr.as_deref() == Some("hanafi") { 2.0 } else { 1.0 };
+    let high_latitude = settings.prayer_high_latitude.as_deref().unwrap_or("angle_based");
+
+    let horizon = horizon_altitude(settings.elevation.unwrap_or(0.0));
+    let sunrise = time_at_altitude(date, latitude, longitude, horizon, true);
+    let sunset = time_at_altitude(date, latitude, longitude, horizon, false);
+    let dhuhr = solar_noon(date, longitude);
+    let asr = time_at_altitude(date, latitude, longitude, asr_altitude(date, latitude, longitude, asr_factor), false);
+    let maghrib = match method.maghrib_degrees {
+        Some(degrees) => time_at_altitude(date, latitude, longitude, -degrees, false),
+        None => sunset,
+    };
+
+    let mut fajr = time_at_altitude(date, latitude, longitude, -method.fajr_degrees, true);
+    let mut isha = match method.isha {
+        Isha::Degrees(degrees) => time_at_altitude(date, latitude, longitude, -degrees, false),
+        Isha::Minutes(minutes) => maghrib.map(|m| m + Duration::minutes(minutes)),
+    };
+
+    if let (Some(sunrise), Some(sunset)) = (sunrise, sunset) {
+        let night = (sunrise + Duration::days(1) - sunset).num_milliseconds() as f64;
+        let limit = |degrees: f64| {
+            night_portion(high_latitude, degrees).map(|p| Duration::milliseconds((night * p) as i64))
+        };
+
+        if let Some(portion) = limit(method.fajr_degrees) {
+            if fajr.map_or(true, |f| sunrise - f > portion) {
+                fajr = Some(sunrise - portion);
+            }
+        }
+        // A fixed interval after Maghrib needs no adjustment
+        if let Isha::Degrees(degrees) = method.isha {
+            if let Some(portion) = limit(degrees) {
+                if isha.map_or(true, |i| i - sunset > portion) {
+                    isha = Some(sunset + portion);
+                }
+            }
+        }
+    }
+
+    let local = |time: Option<DateTime<Utc>>| time.map(|t| t.with_timezone(&Local));
+    let imsak = settings
+        .prayer_imsak
+        .unwrap_or(false)
+        .then(|| fajr.map(|f| f - Duration::minutes(IMSAK_MINUTES)))
+        .flatten();
+
+    Ok(PrayerTimes {
+        method: method.name.to_string(),
+        imsak: local(imsak),
+        fajr: local(fajr),
+        sunrise: local(sunrise),
+        dhuhr: dhuhr.with_timezone(&Local),
+        asr: local(asr),
+        maghrib: local(maghrib),
+        isha: local(isha),
+        next_prayer: None,
+        next_prayer_time: None,
+    })
+}
+
+// Today's times, with the next of the five prayers after `now` (tomorrow's Fajr once Isha has passed)
+pub fn prayer_times_with_next(now: DateTime<Local>, settings: &UserSettings) -> Result<PrayerTimes, String> {
+    let mut today = prayer_times(now.date_naive(), settings)?;
+    let upcoming = [
+        ("Fajr", today.fajr),
+        ("Dhuhr", Some(today.dhuhr)),
+        ("Asr", today.asr),
+        ("Maghrib", today.maghrib),
+        ("Isha", today.isha),
+    ]
+    .into_iter()
+    .find_map(|(name, time)| time.filter(|t| *t > now).map(|t| (name, t)));
+
+    let next = match upcoming {
+        Some(next) => Some(next),
+        None => prayer_times(now.date_naive() + Duration::days(1), settings)?.fajr.map(|t| ("Fajr", t)),
+    };
+    today.next_prayer = next.map(|(name, _)| name.to_string());
+    today.next_prayer_time = next.map(|(_, time)| time);
+    Ok(today)
+}
@@ -0,0 +1,93 @@
+// roman.rs
+// Julian date in classical Roman notation (Kalends, Nones, Ides), year ab urbe condita
+
+use super::julian::{days_in_julian_month, is_julian_leap_year, julian_from_absolute};
+use crate::models::CalendarDate;
+use crate::utils::roman_numeral;
+use chrono::{DateTime, Datelike, Local};
+
+pub struct RomanCalendar;
+
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Ian.", "Feb.", "Mart.", "Apr.", "Mai.", "Iun.",
+    "Iul.", "Aug.", "Sept.", "Oct.", "Nov.", "Dec.",
+];
+
+// Founding of Rome: 1 AD is 754 AUC
+const AUC_OFFSET: i32 = 753;
+
+// In March, May, July and October the Nones fall on the 7th and the Ides on the 15th
+fn nones_and_ides(month: u32) -> (u32, u32) {
+    match month {
+        3 | 5 | 7 | 10 => (7, 15),
+        _ => (5, 13),
+    }
+}
+
+// Counts inclusively back from the next named day: "a.d. XVI Kal. Nov.", "pr. Id. Mart."
+fn roman_day(year: i32, month: u32, day: u32) -> String {
+    let (nones, ides) = nones_and_ides(month);
+    let abbr = MONTH_ABBREVIATIONS[(month - 1) as usize];
+    let next_abbr = MONTH_ABBREVIATIONS[(month % 12) as usize];
+
+    if day == 1 {
+        return format!("Kal. {}", abbr);
+    }
+    if day == nones {
+        return format!("Non. {}", abbr);
+    }
+    if day == ides {
+        return format!("Id. {}", abbr);
+    }
+
+    let (count, target) = if day < nones {
+        (nones - day + 1, format!("Non. {}", abbr))
+    } else if day < ides {
+        (ides - day + 1, format!("Id. {}", abbr))
+    } else if month == 2 && is_julian_leap_year(year) {
+        // The leap day doubles a.d. VI Kal. Mart., so 24 February is "bis sextum"
+        if day == 24 {
+            return format!("a.d. bis VI Kal. {}", next_abbr);
+        }
+        let count = if day > 24 { 31 - day } else { 30 - day };
+        (count, format!("Kal. {}", next_abbr))
+    } else {
+        (days_in_julian_month(year, month) - day + 2, format!("Kal. {}", next_abbr))
+    };
+
+    if count == 2 {
+        format!("pr. {}", target)
+    } else {
+        format!("a.d. {} {}", roman_numeral(count), target)
+    }
+}
+
+impl super::Calendar for RomanCalendar {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let abs = date.naive_local().date().num_days_from_ce() as i64;
+        let (year, month, day) = julian_from_absolute(abs);
+        let auc_year = (year + AUC_OFFSET).max(1) as u32;
+
+        let date_str = format!(
+            "{} {} a.u.c.",
+            roman_day(year, month, day),
+            roman_numeral(auc_year)
+        );
+
+        // Nundinal letters A–H run through the year from 1 January, as in the Fasti
+        let show_nundinal = settings.and_then(|s| s.roman_show_nundinal_letter).unwrap_or(false);
+        let additional_info = if show_nundinal {
+            let day_of_year: u32 = (1..month).map(|m| days_in_julian_month(year, m)).sum::<u32>() + day;
+            let letter = (b'A' + ((day_of_year - 1) % 8) as u8) as char;
+            Some(format!("Nundinal letter {} · {} AUC", letter, auc_year))
+        } else {
+            Some(format!("{} AUC", auc_year))
+        };
+
+        CalendarDate {
+            system: "Roman".to_string(),
+            date: date_str,
+            additional_info,
+        }
+    }
+}
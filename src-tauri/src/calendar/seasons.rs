@@ -0,0 +1,128 @@
+// seasons.rs
+// Astronomical and meteorological seasons, with the solstices, equinoxes and cross-quarter days
+// of the Wheel of the Year
+
+use super::format_time;
+use crate::astronomy::seasons::{season_instant, solar_longitude_instant, Cardinal};
+use crate::models::CalendarDate;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc};
+
+pub struct SeasonsCalendar;
+
+// Festivals of the Wheel of the Year in the northern hemisphere, every 45° of the sun's longitude
+// from the March equinox; the southern hemisphere keeps them half a year apart
+const WHEEL: [&str; 8] = ["Ostara", "Beltane", "Litha", "Lughnasadh", "Mabon", "Samhain", "Yule", "Imbolc"];
+
+const SEASONS: [&str; 4] = ["Spring", "Summer", "Autumn", "Winter"];
+
+struct Event {
+    instant: DateTime<Utc>,
+    // Multiple of 45° of solar longitude
+    octant: usize,
+}
+
+impl Event {
+    fn cardinal(&self) -> Option<Cardinal> {
+        (self.octant % 2 == 0).then(|| Cardinal::ALL[self.octant / 2])
+    }
+
+    fn name(&self, southern: bool) -> String {
+        let festival = WHEEL[(self.octant + if southern { 4 } else { 0 }) % 8];
+        match self.cardinal() {
+            Some(cardinal) => format!("{} ({})", capitalize(cardinal.name()), festival),
+            None => festival.to_string(),
+        }
+    }
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+// The eight quarter and cross-quarter instants of a Gregorian year, in order of solar longitude
+fn events_of_year(year: i32) -> Vec<Event> {
+    Cardinal::ALL
+        .iter()
+        .enumerate()
+        .flat_map(|(quarter, &cardinal)| {
+            let start = season_instant(year, cardinal);
+            // The cross-quarter day falls about 46 days after each equinox or solstice
+            let cross = solar_longitude_instant(cardinal.longitude() + 45.0, start + Duration::days(46));
+            [Event { instant: start, octant: quarter * 2 }, Event { instant: cross, octant: quarter * 2 + 1 }]
+        })
+        .collect()
+}
+
+// Astronomical season begun by the equinox or solstice, in the observer's hemisphere
+fn season_name(cardinal: Cardinal, southern: bool) -> &'static str {
+    let index = Cardinal::ALL.iter().position(|&c| c == cardinal).unwrap_or(0);
+    SEASONS[(index + if southern { 2 } else { 0 }) % 4]
+}
+
+// Meteorological seasons are whole months: March to May is spring in the north
+fn meteorological_season(date: NaiveDate, southern: bool) -> &'static str {
+    let index = (date.month0() as usize + 10) % 12 / 3;
+    SEASONS[(index + if southern { 2 } else { 0 }) % 4]
+}
+
+fn countdown(days: i64) -> String {
+    match days {
+        1 => "tomorrow".to_string(),
+        n => format!("in {} days", n),
+    }
+}
+
+impl super::Calendar for SeasonsCalendar {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let now = date.with_timezone(&Utc);
+        let today = date.date_naive();
+        let southern = settings.and_then(|s| s.latitude).is_some_and(|latitude| latitude < 0.0);
+        let local_date = |instant: DateTime<Utc>| instant.with_timezone(&Local).date_naive();
+
+        let events: Vec<Event> = (today.year() - 1..=today.year() + 1).flat_map(events_of_year).collect();
+        let cardinals: Vec<(Cardinal, &Event)> = events.iter().filter_map(|e| Some((e.cardinal()?, e))).collect();
+        let (cardinal, current) = cardinals
+            .iter()
+            .rev()
+            .find(|(_, e)| local_date(e.instant) <= today)
+            .copied()
+            .expect("last year's December solstice falls before today");
+        let (_, following) = cardinals
+            .iter()
+            .find(|(_, e)| local_date(e.instant) > today)
+            .copied()
+            .expect("next year's March equinox falls after today");
+
+        let season = season_name(cardinal, southern);
+        let day = (today - local_date(current.instant)).num_days() + 1;
+        let length = (local_date(following.instant) - local_date(current.instant)).num_days();
+        let mut heading = format!("{} · day {} of {}", season, day, length);
+        if let Some(event) = events.iter().find(|e| local_date(e.instant) == today) {
+            heading = format!("{} at {} · {}", event.name(southern), format_time(event.instant, settings), heading);
+        }
+
+        let mut info = vec![format!("Meteorological {}", meteorological_season(today, southern).to_lowercase())];
+        // The next cross-quarter day and the next equinox or solstice, in order
+        let upcoming = events.iter().filter(|e| e.instant > now && local_date(e.instant) > today);
+        let next_cross = upcoming.clone().find(|e| e.cardinal().is_none());
+        let next_cardinal = upcoming.clone().find(|e| e.cardinal().is_some());
+        let mut next: Vec<&Event> = next_cross.into_iter().chain(next_cardinal).collect();
+        next.sort_by_key(|e| e.instant);
+        info.extend(next.into_iter().map(|event| {
+            format!(
+                "{} {} {}, {}",
+                event.name(southern),
+                event.instant.with_timezone(&Local).format("%a %-d %b"),
+                format_time(event.instant, settings),
+                countdown((local_date(event.instant) - today).num_days())
+            )
+        }));
+
+        CalendarDate {
+            system: "Seasons".to_string(),
+            date: heading,
+            additional_info: Some(info.join(" · ")),
+        }
+    }
+}
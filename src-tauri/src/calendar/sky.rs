@@ -0,0 +1,191 @@
+// sky.rs
+// Sky tonight: the naked-eye planets above the horizon after dark, close pairings with each other and the
+// moon, and the next solar and lunar eclipses
+
+use super::{format_time, local_time};
+use crate::astronomy::eclipses::{local_circumstances, next_eclipse, Eclipse};
+use crate::astronomy::{altitude, julian_day};
+use crate::astronomy::moon::lunar_position;
+use crate::astronomy::planets::{planet_altitude, planet_position, planet_rise_and_set, separation, PLANET_COUNT};
+use crate::astronomy::sun::time_at_altitude;
+use crate::models::{CalendarDate, UserSettings};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+
+pub struct SkyCalendar;
+
+// Night runs from the end of civil twilight to its return
+const CIVIL_TWILIGHT: f64 = -6.0;
+// Bodies closer than this, in degrees, are worth pointing out
+const CONJUNCTION_DEGREES: f64 = 3.0;
+
+// Tonight's dark hours; where twilight lasts all night or all day, the evening and small hours instead
+fn tonight(today: NaiveDate, latitude: f64, longitude: f64) -> (DateTime<Utc>, DateTime<Utc>) {
+    let dusk = time_at_altitude(today, latitude, longitude, CIVIL_TWILIGHT, false);
+    let dawn = time_at_altitude(today + Duration::days(1), latitude, longitude, CIVIL_TWILIGHT, true);
+    match (dusk, dawn) {
+        (Some(dusk), Some(dawn)) if dawn > dusk => (dusk, dawn),
+        _ => (local_time(today, 21), local_time(today + Duration::days(1), 3)),
+    }
+}
+
+fn eclipse_note(eclipse: &Eclipse, location: Option<(f64, f64)>, settings: Option<&UserSettings>) -> String {
+    let heading = format!(
+        "{} {} eclipse {}",
+        eclipse.kind.name(),
+        if eclipse.solar { "solar" } else { "lunar" },
+        eclipse.greatest.with_timezone(&Local).format("%a %-d %b %Y"),
+    );
+    let Some((latitude, longitude)) = location else {
+        return heading;
+    };
+    match local_circumstances(eclipse, latitude, longitude) {
+        Some((time, magnitude)) if eclipse.solar => format!(
+            "{}, visible here (magnitude {:.2} at {})",
+            heading,
+            magnitude.min(1.0),
+            format_time(time, settings)
+        ),
+        Some((time, _)) => format!("{}, visible here at {}", heading, format_time(time, settings)),
+        None => format!("{}, not visible here", heading),
+    }
+}
+
+impl super::Calendar for SkyCalendar {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let now = date.with_timezone(&Utc);
+        let today = date.date_naive();
+        let location = settings.and_then(|s| s.latitude.zip(s.longitude));
+        let eclipses = [next_eclipse(now, true), next_eclipse(now, false)];
+        let eclipse_notes = eclipses.iter().map(|e| eclipse_note(e, location, settings));
+
+        let Some((latitude, longitude)) = location else {
+            return CalendarDate {
+                system: "Sky Tonight".to_string(),
+                date: "Set latitude and longitude in settings.json to see tonight's planets".to_string(),
+                additional_info: Some(eclipse_notes.collect::<Vec<_>>().join(" · ")),
+            };
+        };
+
+        let (dusk, dawn) = tonight(today, latitude, longitude);
+        let samples: Vec<DateTime<Utc>> = (0..)
+            .map(|i| dusk + Duration::minutes(15 * i))
+            .take_while(|time| *time <= dawn)
+            .collect();
+        let visible: Vec<usize> = (0..PLANET_COUNT)
+            .filter(|&index| samples.iter().any(|&time| planet_altitude(index, time, latitude, longitude) > 0.0))
+            .collect();
+
+        let midnight = dusk + (dawn - dusk) / 2;
+        let jd = julian_day(midnight);
+        let mut info: Vec<String> = visible
+            .iter()
+            .map(|&index| {
+                let name = planet_position(index, jd).name;
+                // Rising and setting from noon to noon, so that an evening set and a morning rise both show
+                let (rise, set) = planet_rise_and_set(
+                    index,
+                    local_time(today, 12),
+                    local_time(today + Duration::days(1), 12),
+                    latitude,
+                    longitude,
+                );
+                match (rise, set) {
+                    (None, None) => format!("{} up all night", name),
+                    _ => {
+                        // In the order they happen, so a planet that is up at dusk reads "sets …, rises …"
+                        let mut events: Vec<(&str, DateTime<Utc>)> = [("rises", rise), ("sets", set)]
+                            .into_iter()
+                            .filter_map(|(label, time)| time.map(|t| (label, t)))
+                            .collect();
+                        events.sort_by_key(|(_, time)| *time);
+                        let times: Vec<String> = events
+                            .into_iter()
+                            .map(|(label, time)| format!("{} {}", label, format_time(time, settings)))
+                            .collect();
+                        format!("{} {}", name, times.join(", "))
+                    }
+                }
+            })
+            .collect();
+
+        // Pairings among the visible planets and the moon, measured in the middle of the night
+        let moon = lunar_position(jd);
+        let mut bodies: Vec<(&str, f64, f64)> = visible
+            .iter()
+            .map(|&index| {
+                let position = planet_position(index, jd);
+                (position.name, position.right_ascension, position.declination)
+            })
+            .collect();
+        let moon_up = samples.iter().any(|&time| {
+            let jd = julian_day(time);
+            let moon = lunar_position(jd);
+            altitude(jd, latitude, longitude, moon.right_ascension, moon.declination) > 0.0
+        });
+        if moon_up {
+            bodies.insert(0, ("Moon", moon.right_ascension, moon.declination));
+        }
+        for (i, first) in bodies.iter().enumerate() {
+            for second in &bodies[i + 1..] {
+                let distance = separation(first.1, first.2, second.1, second.2);
+                if distance < CONJUNCTION_DEGREES {
+                    info.push(format!("{} {:.1}° from {}", first.0, distance, second.0));
+                }
+            }
+        }
+        info.extend(eclipse_notes);
+
+        let names: Vec<&str> = visible.iter().map(|&index| planet_position(index, jd).name).collect();
+        CalendarDate {
+            system: "Sky Tonight".to_string(),
+            date: if names.is_empty() {
+                "No planets up tonight".to_string()
+            } else {
+                format!("Up tonight: {}", names.join(", "))
+            },
+            additional_info: Some(info.join(" · ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Calendar;
+    use chrono::TimeZone;
+
+    #[test]
+    fn finds_the_dark_hours() {
+        // London at midsummer: civil dusk about 21:07 UT, dawn about 02:56 UT
+        let (dusk, dawn) = tonight(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(), 51.51, -0.13);
+        let expected = Utc.with_ymd_and_hms(2024, 6, 21, 21, 7, 0).unwrap();
+        assert!((dusk - expected).num_minutes().abs() < 5, "{}", dusk);
+        let expected = Utc.with_ymd_and_hms(2024, 6, 22, 2, 56, 0).unwrap();
+        assert!((dawn - expected).num_minutes().abs() < 5, "{}", dawn);
+
+        // Tromsø has no dark hours at midsummer, so the evening and small hours stand in
+        let (dusk, dawn) = tonight(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(), 69.65, 18.96);
+        assert_eq!(dawn - dusk, Duration::hours(6));
+    }
+
+    #[test]
+    fn lists_planets_pairings_and_eclipses() {
+        let settings = UserSettings {
+            latitude: Some(40.71),
+            longitude: Some(-74.01),
+            ..Default::default()
+        };
+        let evening = Local.with_ymd_and_hms(2020, 12, 21, 18, 0, 0).unwrap();
+        let sky = SkyCalendar.convert(&evening, Some(&settings));
+        let info = sky.additional_info.unwrap();
+        // The great conjunction, then the annular eclipse of 10 June 2021 and the total lunar eclipse of 26 May
+        assert!(info.contains("Jupiter 0.1° from Saturn"), "{}", info);
+        assert!(info.contains("Annular solar eclipse"), "{}", info);
+        assert!(info.contains("Total lunar eclipse"), "{}", info);
+
+        let sky = SkyCalendar.convert(&evening, None);
+        assert!(sky.date.starts_with("Set latitude and longitude"), "{}", sky.date);
+        let info = sky.additional_info.unwrap();
+        assert!(info.contains("solar eclipse") && info.contains("lunar eclipse"), "{}", info);
+    }
+}
@@ -0,0 +1,104 @@
+// sun_times.rs
+// Sunrise, sunset, solar noon, twilight and day length for the configured location
+
+use super::zmanim::format_zman;
+use crate::astronomy::sun::{horizon_altitude, noon_altitude, solar_noon, time_at_altitude};
+use crate::models::{SunTimes, UserSettings};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+
+// Depressions of the sun's centre that end civil, nautical and astronomical twilight
+const CIVIL_TWILIGHT: f64 = -6.0;
+const NAUTICAL_TWILIGHT: f64 = -12.0;
+const ASTRONOMICAL_TWILIGHT: f64 = -18.0;
+
+fn location(settings: &UserSettings) -> Result<(f64, f64), String> {
+    match (settings.latitude, settings.longitude) {
+        (Some(latitude), Some(longitude)) => Ok((latitude, longitude)),
+        _ => Err("Set latitude and longitude in settings.json to compute sunrise and sunset".to_string()),
+    }
+}
+
+// How long the sun's upper limb is above the horizon, with "polar_day" or "polar_night" where it does not
+// rise or set: a whole day under the midnight sun, none in polar night
+fn day_length(date: NaiveDate, latitude: f64, longitude: f64, horizon: f64) -> (Duration, Option<&'static str>) {
+    let sunrise = time_at_altitude(date, latitude, longitude, horizon, true);
+    let sunset = time_at_altitude(date, latitude, longitude, horizon, false);
+    match (sunrise, sunset) {
+        (Some(sunrise), Some(sunset)) => (sunset - sunrise, None),
+        _ if noon_altitude(date, latitude, longitude) > horizon => (Duration::days(1), Some("polar_day")),
+        _ => (Duration::zero(), Some("polar_night")),
+    }
+}
+
+pub fn sun_times(date: NaiveDate, settings: &UserSettings) -> Result<SunTimes, String> {
+    let (latitude, longitude) = location(settings)?;
+    // Elevation only moves sunrise and sunset; the twilights are measured by depression
+    let horizon = horizon_altitude(settings.elevation.unwrap_or(0.0));
+    let at = |altitude: f64, morning: bool| {
+        time_at_altitude(date, latitude, longitude, altitude, morning).map(|t: DateTime<Utc>| t.with_timezone(&Local))
+    };
+
+    let (length, polar) = day_length(date, latitude, longitude, horizon);
+    let (yesterday, _) = day_length(date - Duration::days(1), latitude, longitude, horizon);
+
+    Ok(SunTimes {
+        astronomical_dawn: at(ASTRONOMICAL_TWILIGHT, true),
+        nautical_dawn: at(NAUTICAL_TWILIGHT, true),
+        civil_dawn: at(CIVIL_TWILIGHT, true),
+        sunrise: at(horizon, true),
+        solar_noon: solar_noon(date, longitude).with_timezone(&Local),
+        sunset: at(horizon, false),
+        civil_dusk: at(CIVIL_TWILIGHT, false),
+        nautical_dusk: at(NAUTICAL_TWILIGHT, false),
+        astronomical_dusk: at(ASTRONOMICAL_TWILIGHT, false),
+        polar: polar.map(str::to_string),
+        day_length_seconds: length.num_seconds(),
+        day_length_change_seconds: (length - yesterday).num_seconds(),
+    })
+}
+
+fn format_length(seconds: i64) -> String {
+    let minutes = (seconds + 30) / 60;
+    format!("{} h {} min", minutes / 60, minutes % 60)
+}
+
+fn format_change(seconds: i64) -> String {
+    let sign = if seconds < 0 { "−" } else { "+" };
+    let seconds = seconds.abs();
+    if seconds >= 60 {
+        format!("{}{} min {} s", sign, seconds / 60, seconds % 60)
+    } else {
+        format!("{}{} s", sign, seconds)
+    }
+}
+
+// One-line summary for the Gregorian calendar's additional info, when "show_sun_times" is on
+pub fn sun_summary(date: NaiveDate, settings: &UserSettings) -> Option<String> {
+    if !settings.show_sun_times.unwrap_or(false) {
+        return None;
+    }
+    let times = sun_times(date, settings).ok()?;
+    let time = |t: Option<DateTime<Local>>| t.map(|t| format_zman(t, settings));
+
+    let mut parts = Vec::new();
+    match times.polar.as_deref() {
+        Some("polar_day") => parts.push("Midnight sun".to_string()),
+        Some(_) => {
+            parts.push("Polar night".to_string());
+            // The sun may still bring twilight at noon
+            if let (Some(dawn), Some(dusk)) = (time(times.civil_dawn), time(times.civil_dusk)) {
+                parts.push(format!("Civil twilight {}–{}", dawn, dusk));
+            }
+        }
+        None => {
+            parts.extend(time(times.sunrise).map(|t| format!("Sunrise {}", t)));
+            parts.extend(time(times.sunset).map(|t| format!("Sunset {}", t)));
+            parts.push(format!(
+                "Day {} ({})",
+                format_length(times.day_length_seconds),
+                format_change(times.day_length_change_seconds)
+            ));
+        }
+    }
+    Some(parts.join(" · "))
+}
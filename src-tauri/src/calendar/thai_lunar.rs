@@ -0,0 +1,328 @@
+// thai_lunar.rs
+// Thai lunar calendar (Chantarakati) from the Suriyayatra arithmetic, with Uposatha days and Buddhist holidays
+
+use crate::models::CalendarDate;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate};
+
+pub struct ThaiLunarCalendar;
+
+// The Chula Sakarat era, on which the Suriyayatra reckons, began in 638 CE
+const CHULA_SAKARAT_OFFSET: i32 = 638;
+
+// 1 waxing of Duean Ai 2025 (the lunar year whose eighth month falls in 2025); other years are counted from here
+const ANCHOR_YEAR: i32 = 2025;
+const ANCHOR_START: (i32, u32, u32) = (2024, 12, 1);
+
+// (Thai name, English ordinal) of the twelve months
+const MONTHS: [(&str, &str); 12] = [
+    ("Duean Ai", "first"),
+    ("Duean Yi", "second"),
+    ("Duean Sam", "third"),
+    ("Duean Si", "fourth"),
+    ("Duean Ha", "fifth"),
+    ("Duean Hok", "sixth"),
+    ("Duean Chet", "seventh"),
+    ("Duean Paet", "eighth"),
+    ("Duean Kao", "ninth"),
+    ("Duean Sip", "tenth"),
+    ("Duean Sip-et", "eleventh"),
+    ("Duean Sip-song", "twelfth"),
+];
+
+// (Thai name, English) of the twelve-year animal cycle, from the year of the rat
+const ANIMAL_YEARS: [(&str, &str); 12] = [
+    ("Pi Chuat", "Rat"),
+    ("Pi Chalu", "Ox"),
+    ("Pi Khan", "Tiger"),
+    ("Pi Tho", "Rabbit"),
+    ("Pi Marong", "Dragon"),
+    ("Pi Maseng", "Snake"),
+    ("Pi Mamia", "Horse"),
+    ("Pi Mamae", "Goat"),
+    ("Pi Wok", "Monkey"),
+    ("Pi Raka", "Rooster"),
+    ("Pi Cho", "Dog"),
+    ("Pi Kun", "Pig"),
+];
+
+/* -------------------------
+    Suriyayatra year types
+    ------------------------- */
+
+// (kammacapon, avoman, tithi) at the start of a Chula Sakarat year
+fn year_parts(cs: i64) -> (i64, i64, i64) {
+    let elapsed = cs * 292207 + 373;
+    let horakhun = elapsed / 800 + 1;
+    let kammacapon = 800 - elapsed % 800;
+    let avoman = (horakhun * 11 + 650) % 692;
+    let tithi = (horakhun + (horakhun * 11 + 650) / 692) % 30;
+    (kammacapon, avoman, tithi)
+}
+
+fn wants_adhikamasa(cs: i64) -> bool {
+    let (_, _, tithi) = year_parts(cs);
+    tithi >= 24 || tithi <= 5
+}
+
+// A year with a second eighth month; when two years in a row qualify, the later one takes it
+fn is_adhikamasa(cs: i64) -> bool {
+    wants_adhikamasa(cs) && !wants_adhikamasa(cs + 1)
+}
+
+fn wants_adhikavara(cs: i64) -> bool {
+    let (kammacapon, avoman, _) = year_parts(cs);
+    if avoman == 137 && year_parts(cs + 1).1 == 0 {
+        return false;
+    }
+    // Solar leap years (kammacapon ≤ 207) have a lower threshold
+    avoman <= if kammacapon <= 207 { 126 } else { 137 }
+}
+
+// A year whose seventh month has 30 days; a year that already has a leap month passes its extra day on
+fn is_adhikavara(cs: i64) -> bool {
+    !is_adhikamasa(cs) && (wants_adhikavara(cs) || (is_adhikamasa(cs - 1) && wants_adhikavara(cs - 1)))
+}
+
+fn year_length(year: i32) -> i64 {
+    let cs = (year - CHULA_SAKARAT_OFFSET) as i64;
+    if is_adhikamasa(cs) {
+        384
+    } else if is_adhikavara(cs) {
+        355
+    } else {
+        354
+    }
+}
+
+// (month, second eighth month, length) in order; odd months have 29 days, even months 30
+fn months_of(year: i32) -> Vec<(u32, bool, i64)> {
+    let cs = (year - CHULA_SAKARAT_OFFSET) as i64;
+    let mut months = Vec::new();
+    for month in 1..=12 {
+        let length = if month % 2 == 0 || (month == 7 && is_adhikavara(cs)) { 30 } else { 29 };
+        months.push((month, false, length));
+        if month == 8 && is_adhikamasa(cs) {
+            months.push((8, true, 30));
+        }
+    }
+    months
+}
+
+/* -------------------------
+    Dates
+    ------------------------- */
+
+// `year` is the Gregorian year of the lunar year's eighth month; it begins around December of the year before
+pub struct ThaiLunarDate {
+    pub year: i32,
+    pub month: u32,
+    pub second_eighth: bool,
+    pub day: u32,
+    pub month_length: u32,
+}
+
+impl ThaiLunarDate {
+    fn waxing(&self) -> bool {
+        self.day <= 15
+    }
+
+    // Day within the waxing or waning half
+    fn half_day(&self) -> u32 {
+        if self.waxing() { self.day } else { self.day - 15 }
+    }
+
+    // Wan Phra: the eighth of each half, the full moon and the last day of the month
+    pub fn is_uposatha(&self) -> bool {
+        self.day == 8 || self.day == 15 || self.day == 23 || self.day == self.month_length
+    }
+
+    // Festival months move one month later in a year with a second eighth month
+    fn is_festival(&self, month: u32, day: u32) -> bool {
+        let leap = is_adhikamasa((self.year - CHULA_SAKARAT_OFFSET) as i64);
+        let month = if leap && month < 8 { month + 1 } else { month };
+        self.month == month && self.day == day && (month != 8 || self.second_eighth == leap)
+    }
+}
+
+fn year_start(year: i32) -> NaiveDate {
+    let (y, m, d) = ANCHOR_START;
+    let mut start = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    for earlier in year..ANCHOR_YEAR {
+        start -= Duration::days(year_length(earlier));
+    }
+    for later in ANCHOR_YEAR..year {
+        start += Duration::days(year_length(later));
+    }
+    start
+}
+
+pub fn thai_lunar_date(date: NaiveDate) -> ThaiLunarDate {
+    let year = if date >= year_start(date.year() + 1) {
+        date.year() + 1
+    } else if date < year_start(date.year()) {
+        date.year() - 1
+    } else {
+        date.year()
+    };
+    let mut offset = (date - year_start(year)).num_days();
+    let months = months_of(year);
+
+    for &(month, second_eighth, length) in &months {
+        if offset < length {
+            return ThaiLunarDate { year, month, second_eighth, day: offset as u32 + 1, month_length: length as u32 };
+        }
+        offset -= length;
+    }
+    unreachable!("date past the end of its lunar year")
+}
+
+fn holiday(date: &ThaiLunarDate) -> Option<&'static str> {
+    if date.is_festival(3, 15) {
+        Some("Magha Puja (Makha Bucha)")
+    } else if date.is_festival(6, 15) {
+        Some("Visakha Puja (Wisakha Bucha)")
+    } else if date.is_festival(6, 23) {
+        Some("Atthami Puja")
+    } else if date.is_festival(8, 15) {
+        Some("Asalha Puja (Asanha Bucha)")
+    } else if date.is_festival(8, 16) {
+        Some("Khao Phansa (beginning of Vassa)")
+    } else if date.is_festival(11, 15) {
+        Some("Ok Phansa (end of Vassa)")
+    } else if date.is_festival(12, 15) {
+        Some("Loy Krathong")
+    } else {
+        None
+    }
+}
+
+fn gregorian_from_thai_lunar(year: i32, month: u32, second_eighth: bool, day: u32) -> NaiveDate {
+    let elapsed: i64 = months_of(year)
+        .iter()
+        .take_while(|&&(m, second, _)| (m, second) != (month, second_eighth))
+        .map(|&(_, _, length)| length)
+        .sum();
+    year_start(year) + Duration::days(elapsed + day as i64 - 1)
+}
+
+// Day of the Vassa rains retreat, which runs from the day after Asalha Puja to Ok Phansa
+fn vassa_day(date: NaiveDate, year: i32) -> Option<i64> {
+    let leap = is_adhikamasa((year - CHULA_SAKARAT_OFFSET) as i64);
+    let begins = gregorian_from_thai_lunar(year, 8, leap, 16);
+    let ends = gregorian_from_thai_lunar(year, 11, false, 15);
+    (date >= begins && date <= ends).then(|| (date - begins).num_days() + 1)
+}
+
+// First Uposatha day on or after `date`
+fn next_uposatha(date: NaiveDate) -> NaiveDate {
+    let mut day = date;
+    while !thai_lunar_date(day).is_uposatha() {
+        day += Duration::days(1);
+    }
+    day
+}
+
+// Lock screen notes for the Buddhist calendar: today's observances, or how far off the next Uposatha is
+pub fn uposatha_notes(date: NaiveDate) -> Vec<String> {
+    let lunar = thai_lunar_date(date);
+    let mut notes = vec![match (next_uposatha(date) - date).num_days() {
+        0 => "Uposatha (Wan Phra)".to_string(),
+        1 => "Next Uposatha tomorrow".to_string(),
+        n => format!("Next Uposatha in {} days", n),
+    }];
+    if let Some(name) = holiday(&lunar) {
+        notes.push(name.to_string());
+    }
+    notes
+}
+
+impl super::Calendar for ThaiLunarCalendar {
+    fn convert(&self, date: &DateTime<Local>, _settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let naive = date.naive_local().date();
+        let lunar = thai_lunar_date(naive);
+        let (month_name, ordinal) =
+            if lunar.second_eighth { ("Duean Paet-Paet", "second eighth") } else { MONTHS[lunar.month as usize - 1] };
+        let (phase, phase_english) = if lunar.waxing() { ("Khuen", "waxing") } else { ("Raem", "waning") };
+
+        // The animal year turns over on 1 waxing of the fifth month
+        let animal_year = if lunar.month >= 5 { lunar.year } else { lunar.year - 1 };
+        let (animal, animal_english) = ANIMAL_YEARS[(animal_year - 4).rem_euclid(12) as usize];
+
+        let mut info = vec![format!(
+            "Day {} {} of the {} month, Year of the {} ({}), BE {}",
+            lunar.half_day(),
+            phase_english,
+            ordinal,
+            animal_english,
+            animal,
+            naive.year() + 543
+        )];
+        if lunar.is_uposatha() {
+            info.push("Uposatha (Wan Phra)".to_string());
+        }
+        if let Some(name) = holiday(&lunar) {
+            info.push(name.to_string());
+        }
+        if let Some(day) = vassa_day(naive, lunar.year) {
+            info.push(format!("Vassa day {}", day));
+        }
+
+        CalendarDate {
+            system: "Thai Lunar".to_string(),
+            date: format!("{} {} Kham, {}", phase, lunar.half_day(), month_name),
+            additional_info: Some(info.join(" · ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Public holidays as announced in Thailand
+    #[test]
+    fn finds_buddhist_holidays() {
+        let cases = [
+            ((2023, 3, 6), "Magha Puja"),
+            ((2023, 6, 3), "Visakha Puja"),
+            ((2023, 8, 1), "Asalha Puja"),
+            ((2023, 8, 2), "Khao Phansa"),
+            ((2023, 10, 29), "Ok Phansa"),
+            ((2024, 2, 24), "Magha Puja"),
+            ((2024, 5, 22), "Visakha Puja"),
+            ((2024, 7, 20), "Asalha Puja"),
+            ((2024, 11, 15), "Loy Krathong"),
+            ((2025, 2, 12), "Magha Puja"),
+            ((2025, 5, 11), "Visakha Puja"),
+            ((2025, 10, 7), "Ok Phansa"),
+            ((2026, 3, 3), "Magha Puja"),
+            ((2026, 7, 29), "Asalha Puja"),
+        ];
+        for ((year, month, day), expected) in cases {
+            let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+            let found = holiday(&thai_lunar_date(date));
+            assert!(found.is_some_and(|name| name.starts_with(expected)), "{}: {:?}", date, found);
+        }
+    }
+
+    #[test]
+    fn keeps_uposatha_on_quarter_days() {
+        // Magha Puja is a full moon, and so a Uposatha day
+        let full_moon = thai_lunar_date(NaiveDate::from_ymd_opt(2025, 2, 12).unwrap());
+        assert!(full_moon.is_uposatha());
+        assert_eq!(uposatha_notes(NaiveDate::from_ymd_opt(2025, 2, 12).unwrap())[0], "Uposatha (Wan Phra)");
+        assert_eq!(uposatha_notes(NaiveDate::from_ymd_opt(2025, 2, 11).unwrap())[0], "Next Uposatha tomorrow");
+    }
+
+    #[test]
+    fn converts_every_day_of_a_century() {
+        let mut date = NaiveDate::from_ymd_opt(1950, 1, 1).unwrap();
+        let mut previous = thai_lunar_date(date);
+        while date < NaiveDate::from_ymd_opt(2050, 1, 1).unwrap() {
+            date += Duration::days(1);
+            let lunar = thai_lunar_date(date);
+            assert!(lunar.day == previous.day + 1 || (lunar.day == 1 && previous.day == previous.month_length), "{}", date);
+            previous = lunar;
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// zmanim.rs
+// Halachic times of day for the configured location
+
+use crate::astronomy::sun::{horizon_altitude, solar_noon, time_at_altitude};
+use crate::models::{UserSettings, Zmanim};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+
+// Depressions of the sun below the horizon for dawn and nightfall
+const ALOT_HASHACHAR_DEGREES: f64 = -16.1;
+const TZEIT_HAKOCHAVIM_DEGREES: f64 = -8.5;
+
+pub fn zmanim(date: NaiveDate, settings: &UserSettings) -> Result<Zmanim, String> {
+    let (latitude, longitude) = match (settings.latitude, settings.longitude) {
+        (Some(latitude), Some(longitude)) => (latitude, longitude),
+        _ => return Err("Set latitude and longitude in settings.json to compute zmanim".to_string()),
+    };
+
+    // Elevation only moves sunrise and sunset; the twilight times are measured by depression
+    let horizon = horizon_altitude(settings.elevation.unwrap_or(0.0));
+    let sunrise = time_at_altitude(date, latitude, longitude, horizon, true);
+    let sunset = time_at_altitude(date, latitude, longitude, horizon, false);
+    let alot_hashachar = time_at_altitude(date, latitude, longitude, ALOT_HASHACHAR_DEGREES, true);
+    let tzeit_hakochavim = match settings.zmanim_tzeit_minutes {
+        Some(minutes) => sunset.map(|s| s + Duration::minutes(minutes as i64)),
+        None => time_at_altitude(date, latitude, longitude, TZEIT_HAKOCHAVIM_DEGREES, false),
+    };
+
+    // Seasonal hours: a twelfth of the day, reckoned by the GRA from sunrise to sunset and by
+    // the Magen Avraham from a fixed number of minutes before sunrise to the same after sunset
+    let seasonal = |start: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>, hours: f64| {
+        let (start, end) = (start?, end?);
+        Some(start + Duration::milliseconds(((end - start).num_milliseconds() as f64 * hours / 12.0) as i64))
+    };
+    let mga = Duration::minutes(settings.zmanim_mga_minutes.unwrap_or(72) as i64);
+    let candle_lighting = Duration::minutes(settings.zmanim_candle_lighting_minutes.unwrap_or(18) as i64);
+
+    let local = |time: Option<DateTime<Utc>>| time.map(|t| t.with_timezone(&Local));
+    Ok(Zmanim {
+        alot_hashachar: local(alot_hashachar),
+        sunrise: local(sunrise),
+        sof_zman_shema_mga: local(seasonal(sunrise.map(|s| s - mga), sunset.map(|s| s + mga), 3.0)),
+        sof_zman_shema_gra: local(seasonal(sunrise, sunset, 3.0)),
+        chatzot: solar_noon(date, longitude).with_timezone(&Local),
+        mincha_gedola: local(seasonal(sunrise, sunset, 6.5)),
+        plag_hamincha: local(seasonal(sunrise, sunset, 10.75)),
+        candle_lighting: local(sunset.map(|s| s - candle_lighting)),
+        sunset: local(sunset),
+        tzeit_hakochavim: local(tzeit_hakochavim),
+    })
+}
+
+// Clock time in the lock screen's format: 24-hour for "military", 12-hour otherwise
+pub fn format_zman(time: DateTime<Local>, settings: &UserSettings) -> String {
+    if settings.date_format.as_deref() == Some("military") {
+        time.format("%H:%M").to_string()
+    } else {
+        time.format("%-I:%M %p").to_string()
+    }
+}
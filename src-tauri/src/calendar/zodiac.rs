@@ -0,0 +1,168 @@
+// zodiac.rs
+// Tropical and sidereal signs of the sun and moon, the moon's nakshatra, and the Chinese animal
+// and element of the year, month and day
+
+use crate::astronomy::julian_day;
+use crate::astronomy::moon::lunar_position;
+use crate::astronomy::sun::solar_position;
+use crate::models::{CalendarDate, UserSettings};
+use crate::utils::append_info;
+use chrono::{DateTime, Datelike, Local, Utc};
+
+pub struct ZodiacCalendar;
+
+// English name, symbol, Sanskrit rashi
+const SIGNS: [(&str, &str, &str); 12] = [
+    ("Aries", "♈", "Mesha"),
+    ("Taurus", "♉", "Vrishabha"),
+    ("Gemini", "♊", "Mithuna"),
+    ("Cancer", "♋", "Karka"),
+    ("Leo", "♌", "Simha"),
+    ("Virgo", "♍", "Kanya"),
+    ("Libra", "♎", "Tula"),
+    ("Scorpio", "♏", "Vrishchika"),
+    ("Sagittarius", "♐", "Dhanu"),
+    ("Capricorn", "♑", "Makara"),
+    ("Aquarius", "♒", "Kumbha"),
+    ("Pisces", "♓", "Meena"),
+];
+
+const NAKSHATRAS: [&str; 27] = [
+    "Ashwini", "Bharani", "Krittika", "Rohini", "Mrigashira", "Ardra", "Punarvasu", "Pushya", "Ashlesha",
+    "Magha", "Purva Phalguni", "Uttara Phalguni", "Hasta", "Chitra", "Swati", "Vishakha", "Anuradha", "Jyeshtha",
+    "Mula", "Purva Ashadha", "Uttara Ashadha", "Shravana", "Dhanishta", "Shatabhisha", "Purva Bhadrapada",
+    "Uttara Bhadrapada", "Revati",
+];
+
+// Ayanamsa at J2000 in degrees, by school; all grow with precession at the same rate
+const AYANAMSAS: [(&str, &str, f64); 4] = [
+    ("lahiri", "Lahiri", 23.853),
+    ("fagan_bradley", "Fagan-Bradley", 24.736),
+    ("krishnamurti", "Krishnamurti", 23.757),
+    ("raman", "Raman", 22.410),
+];
+const PRECESSION_PER_YEAR: f64 = 50.2788 / 3600.0;
+
+const STEMS: [(&str, &str); 10] = [
+    ("甲", "Wood"), ("乙", "Wood"), ("丙", "Fire"), ("丁", "Fire"), ("戊", "Earth"),
+    ("己", "Earth"), ("庚", "Metal"), ("辛", "Metal"), ("壬", "Water"), ("癸", "Water"),
+];
+const BRANCHES: [(&str, &str); 12] = [
+    ("子", "Rat"), ("丑", "Ox"), ("寅", "Tiger"), ("卯", "Rabbit"), ("辰", "Dragon"), ("巳", "Snake"),
+    ("午", "Horse"), ("未", "Goat"), ("申", "Monkey"), ("酉", "Rooster"), ("戌", "Dog"), ("亥", "Pig"),
+];
+
+// Sun's longitude at Lichun, where the Chinese solar year and its first (Tiger) month begin
+const LICHUN_LONGITUDE: f64 = 315.0;
+
+fn sign(longitude: f64) -> usize {
+    (longitude.rem_euclid(360.0) / 30.0) as usize % 12
+}
+
+fn ayanamsa(key: Option<&str>, jd: f64) -> (&'static str, f64) {
+    let key = key.unwrap_or("lahiri").trim().to_lowercase();
+    let (_, name, at_j2000) = AYANAMSAS.iter().find(|a| a.0 == key).copied().unwrap_or(AYANAMSAS[0]);
+    (name, at_j2000 + (jd - 2451545.0) / 365.25 * PRECESSION_PER_YEAR)
+}
+
+// A stem-branch pair of the sixty-fold cycle, as "丙午 Yang Fire Horse"
+fn sexagenary(stem: usize, branch: usize) -> String {
+    let (stem_char, element) = STEMS[stem % 10];
+    let (branch_char, animal) = BRANCHES[branch % 12];
+    let polarity = if stem % 2 == 0 { "Yang" } else { "Yin" };
+    format!("{}{} {} {} {}", stem_char, branch_char, polarity, element, animal)
+}
+
+struct Zodiac {
+    sun: usize,
+    moon: usize,
+    ayanamsa: &'static str,
+    sidereal_sun: usize,
+    sidereal_moon: usize,
+    nakshatra: usize,
+    pada: usize,
+    // Indices into the sixty-fold cycle as (stem, branch)
+    year: (usize, usize),
+    month: (usize, usize),
+    day: (usize, usize),
+}
+
+fn zodiac(date: &DateTime<Local>, settings: Option<&UserSettings>) -> Zodiac {
+    let jd = julian_day(date.with_timezone(&Utc));
+    let sun = solar_position(jd).longitude;
+    let moon = lunar_position(jd).longitude;
+    let (ayanamsa, offset) = ayanamsa(settings.and_then(|s| s.zodiac_ayanamsa.as_deref()), jd);
+    let sidereal_moon = (moon - offset).rem_euclid(360.0);
+    let nakshatra_span = 360.0 / 27.0;
+
+    // The solar year and months turn at the jie terms, so early January still belongs to the year before
+    let month = ((sun - LICHUN_LONGITUDE).rem_euclid(360.0) / 30.0) as usize % 12;
+    let year = if date.month() <= 2 && month >= 10 { date.year() - 1 } else { date.year() };
+    let year_index = (year - 4).rem_euclid(60) as usize;
+    // The Tiger month's stem follows from the year's ("five tigers" rule)
+    let month_stem = (year_index % 5) * 2 + 2 + month;
+    // 1 October 1949 was a jiazi day
+    let day_index = (date.date_naive().num_days_from_ce() as i64 + 1721474).rem_euclid(60) as usize;
+
+    Zodiac {
+        sun: sign(sun),
+        moon: sign(moon),
+        ayanamsa,
+        sidereal_sun: sign(sun - offset),
+        sidereal_moon: sign(sidereal_moon),
+        nakshatra: (sidereal_moon / nakshatra_span) as usize % 27,
+        pada: ((sidereal_moon % nakshatra_span) / (nakshatra_span / 4.0)) as usize + 1,
+        year: (year_index % 10, year_index % 12),
+        month: (month_stem % 10, month + 2),
+        day: (day_index % 10, day_index % 12),
+    }
+}
+
+fn tropical(sign: usize) -> String {
+    format!("{} {}", SIGNS[sign].0, SIGNS[sign].1)
+}
+
+fn sidereal(sign: usize) -> String {
+    format!("{} ({})", SIGNS[sign].2, SIGNS[sign].0)
+}
+
+impl super::Calendar for ZodiacCalendar {
+    fn convert(&self, date: &DateTime<Local>, settings: Option<&crate::models::UserSettings>) -> CalendarDate {
+        let z = zodiac(date, settings);
+        let info = [
+            format!(
+                "Sidereal ({}): Sun in {}, Moon in {}",
+                z.ayanamsa,
+                sidereal(z.sidereal_sun),
+                sidereal(z.sidereal_moon)
+            ),
+            format!("Nakshatra {}, pada {}", NAKSHATRAS[z.nakshatra], z.pada),
+            format!("Year {}", sexagenary(z.year.0, z.year.1)),
+            format!("Month {}", sexagenary(z.month.0, z.month.1)),
+            format!("Day {}", sexagenary(z.day.0, z.day.1)),
+        ];
+
+        CalendarDate {
+            system: "Zodiac".to_string(),
+            date: format!("Sun in {} · Moon in {}", tropical(z.sun), tropical(z.moon)),
+            additional_info: Some(info.join(" · ")),
+        }
+    }
+}
+
+// Adds the signs and the Chinese year to the calendar chosen by "zodiac_calendar", if any
+pub fn annotate(calendar: &str, date: &mut CalendarDate, now: &DateTime<Local>, settings: &UserSettings) {
+    if settings.zodiac_calendar.as_deref() != Some(calendar) {
+        return;
+    }
+    let z = zodiac(now, Some(settings));
+    let (_, element) = STEMS[z.year.0];
+    let (_, animal) = BRANCHES[z.year.1];
+    append_info(
+        date,
+        vec![
+            format!("Sun in {}, Moon in {}", tropical(z.sun), tropical(z.moon)),
+            format!("Year of the {} {}", element, animal),
+        ],
+    );
+}
@@ -2,13 +2,31 @@
 // Maintains calendar commands
 
 use crate::calendar::{
+    anniversaries::upcoming_anniversaries,
     buddhist::BuddhistCalendar,
+    byzantine::ByzantineCalendar,
+    chronology::ChronologyCalendar,
     french_revolutionary::FrenchRevolutionaryCalendar,
     gregorian::GregorianCalendar,
+    historical::HistoricalCalendar,
+    icelandic::IcelandicCalendar,
     jewish::JewishCalendar,
     julian::JulianCalendar,
+    liturgical::LiturgicalCalendar,
+    microseasons::MicroseasonsCalendar,
+    moon::MoonCalendar,
+    prayer_times::prayer_times_with_next,
+    roman::RomanCalendar,
+    seasons::SeasonsCalendar,
+    sky::SkyCalendar,
+    sun_times::sun_times,
+    thai_lunar::ThaiLunarCalendar,
+    zmanim::zmanim,
+    zodiac::{self, ZodiacCalendar},
 };
-use crate::models::CalendarDate;
+use crate::clocks::read_clocks;
+use crate::models::{CalendarDate, ClockReading, ClockSettings, HebrewAnniversary, PrayerTimes, SunTimes, Zmanim};
+use crate::namedays::annotate;
 use crate::settings::load_settings;
 use std::collections::HashMap;
 
@@ -21,16 +39,30 @@ pub fn get_current_dates() -> Result<Vec<CalendarDate>, String> {
     let calendars: HashMap<&str, Box<dyn crate::calendar::Calendar>> = [
         ("gregorian", Box::new(GregorianCalendar) as Box<dyn crate::calendar::Calendar>),
         ("julian", Box::new(JulianCalendar)),
+        ("historical", Box::new(HistoricalCalendar)),
+        ("roman", Box::new(RomanCalendar)),
+        ("byzantine", Box::new(ByzantineCalendar)),
+        ("icelandic", Box::new(IcelandicCalendar)),
         ("buddhist", Box::new(BuddhistCalendar)),
+        ("thai_lunar", Box::new(ThaiLunarCalendar)),
         ("french_revolutionary", Box::new(FrenchRevolutionaryCalendar)),
-        ("jewish", Box::new(JewishCalendar::new())),
+        ("jewish", Box::new(JewishCalendar)),
+        ("liturgical", Box::new(LiturgicalCalendar)),
+        ("chronology", Box::new(ChronologyCalendar)),
+        ("moon", Box::new(MoonCalendar)),
+        ("seasons", Box::new(SeasonsCalendar)),
+        ("microseasons", Box::new(MicroseasonsCalendar)),
+        ("zodiac", Box::new(ZodiacCalendar)),
+        ("sky", Box::new(SkyCalendar)),
     ]
     .into_iter()
     .collect();
 
     for calendar_system in &settings.enabled_calendars {
         if let Some(calendar) = calendars.get(calendar_system.as_str()) {
-            let date = calendar.convert(&now, Some(&settings));
+            let mut date = calendar.convert(&now, Some(&settings));
+            annotate(calendar_system, &mut date, now.date_naive(), &settings);
+            zodiac::annotate(calendar_system, &mut date, &now, &settings);
             dates.push(date);
         }
     }
@@ -43,8 +75,59 @@ pub fn get_available_calendar_plugins() -> Result<Vec<String>, String> {
     Ok(vec![
         "gregorian".to_string(),
         "julian".to_string(),
+        "historical".to_string(),
+        "roman".to_string(),
+        "byzantine".to_string(),
+        "icelandic".to_string(),
         "buddhist".to_string(),
+        "thai_lunar".to_string(),
         "french_revolutionary".to_string(),
         "jewish".to_string(),
+        "liturgical".to_string(),
+        "chronology".to_string(),
+        "moon".to_string(),
+        "seasons".to_string(),
+        "microseasons".to_string(),
+        "zodiac".to_string(),
+        "sky".to_string(),
     ])
-}
\ No newline at end of file
+}
+
+// The alternative clocks chosen in settings.json; polled by the lock screen every second, so it takes the
+// clock settings the screen has already loaded rather than reading the file on every tick
+#[tauri::command]
+pub fn get_clocks(settings: ClockSettings) -> Vec<ClockReading> {
+    read_clocks(chrono::Local::now(), &settings)
+}
+
+// Today's sunrise, sunset, twilight and day length for the location in settings.json
+#[tauri::command]
+pub fn get_sun_times() -> Result<SunTimes, String> {
+    let settings = load_settings()?;
+    sun_times(chrono::Local::now().date_naive(), &settings)
+}
+
+// Today's halachic times for the location in settings.json
+#[tauri::command]
+pub fn get_zmanim() -> Result<Zmanim, String> {
+    let settings = load_settings()?;
+    zmanim(chrono::Local::now().date_naive(), &settings)
+}
+
+// Today's Islamic prayer times for the location in settings.json, and the next prayer
+#[tauri::command]
+pub fn get_prayer_times() -> Result<PrayerTimes, String> {
+    let settings = load_settings()?;
+    prayer_times_with_next(chrono::Local::now(), &settings)
+}
+
+// Most anniversaries a single get_hebrew_anniversaries call returns
+const MAX_ANNIVERSARIES: u32 = 100;
+
+// The next `count` Hebrew birthdays, bar/bat mitzvahs and yahrzeits of the people in settings.json
+#[tauri::command]
+pub fn get_hebrew_anniversaries(count: u32) -> Result<Vec<HebrewAnniversary>, String> {
+    let settings = load_settings()?;
+    let count = count.min(MAX_ANNIVERSARIES) as usize;
+    Ok(upcoming_anniversaries(&settings, chrono::Local::now().date_naive(), count))
+}
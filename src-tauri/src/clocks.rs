@@ -0,0 +1,140 @@
+// clocks.rs
+// Alternative ways of telling the time, shown beside the lock screen clock
+
+use crate::astronomy::sun::{horizon_altitude, time_at_altitude};
+use crate::astronomy::{julian_day, sidereal_time};
+use crate::models::{ClockReading, ClockSettings};
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDate, Timelike, Utc};
+
+pub trait Clock {
+    fn label(&self) -> &'static str;
+    // None where the clock cannot be read, such as without a location or on a day the sun does not rise
+    fn read(&self, now: DateTime<Local>, settings: &ClockSettings) -> Option<String>;
+}
+
+fn show_seconds(settings: &ClockSettings) -> bool {
+    settings.show_seconds.unwrap_or(false)
+}
+
+fn format_hours(hours: f64, show_seconds: bool) -> String {
+    let seconds = (hours * 3600.0).floor() as i64;
+    if show_seconds {
+        format!("{:02}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+    } else {
+        format!("{:02}:{:02}", seconds / 3600, seconds / 60 % 60)
+    }
+}
+
+// Swatch Internet Time: a thousand .beats a day, kept on Biel Mean Time (UTC+1) without daylight saving
+pub struct BeatsClock;
+
+impl Clock for BeatsClock {
+    fn label(&self) -> &'static str {
+        ".beats"
+    }
+
+    fn read(&self, now: DateTime<Local>, settings: &ClockSettings) -> Option<String> {
+        let biel = now.with_timezone(&FixedOffset::east_opt(3600)?);
+        let seconds = biel.num_seconds_from_midnight() as f64 + biel.nanosecond() as f64 / 1e9;
+        let beats = seconds / 86.4;
+        Some(if show_seconds(settings) {
+            format!("@{:06.2}", (beats * 100.0).floor() / 100.0)
+        } else {
+            format!("@{:03}", beats.floor() as u32)
+        })
+    }
+}
+
+pub struct UtcClock;
+
+impl Clock for UtcClock {
+    fn label(&self) -> &'static str {
+        "UTC"
+    }
+
+    fn read(&self, now: DateTime<Local>, settings: &ClockSettings) -> Option<String> {
+        let format = if show_seconds(settings) { "%H:%M:%S" } else { "%H:%M" };
+        Some(now.with_timezone(&Utc).format(format).to_string())
+    }
+}
+
+// Sidereal time at Greenwich, or at the configured longitude
+pub struct SiderealClock {
+    pub local: bool,
+}
+
+impl Clock for SiderealClock {
+    fn label(&self) -> &'static str {
+        if self.local {
+            "Local sidereal"
+        } else {
+            "Greenwich sidereal"
+        }
+    }
+
+    fn read(&self, now: DateTime<Local>, settings: &ClockSettings) -> Option<String> {
+        let longitude = if self.local { settings.longitude? } else { 0.0 };
+        let degrees = (sidereal_time(julian_day(now.with_timezone(&Utc))) + longitude).rem_euclid(360.0);
+        Some(format_hours(degrees / 15.0, show_seconds(settings)))
+    }
+}
+
+// Equal hours counted from the last sunrise (Babylonian) or the last sunset (Italian)
+pub struct SunHoursClock {
+    pub from_sunset: bool,
+}
+
+impl SunHoursClock {
+    fn epoch(&self, date: NaiveDate, settings: &ClockSettings) -> Option<DateTime<Utc>> {
+        let (latitude, longitude) = settings.latitude.zip(settings.longitude)?;
+        let horizon = horizon_altitude(settings.elevation.unwrap_or(0.0));
+        time_at_altitude(date, latitude, longitude, horizon, !self.from_sunset)
+    }
+}
+
+impl Clock for SunHoursClock {
+    fn label(&self) -> &'static str {
+        if self.from_sunset {
+            "Italian hours"
+        } else {
+            "Babylonian hours"
+        }
+    }
+
+    fn read(&self, now: DateTime<Local>, settings: &ClockSettings) -> Option<String> {
+        let now = now.with_timezone(&Utc);
+        let today = now.with_timezone(&Local).date_naive();
+        let epoch = match self.epoch(today, settings)? {
+            epoch if epoch <= now => epoch,
+            _ => self.epoch(today - Duration::days(1), settings)?,
+        };
+        let hours = (now - epoch).num_milliseconds() as f64 / 3_600_000.0;
+        Some(format_hours(hours, show_seconds(settings)))
+    }
+}
+
+fn clock(key: &str) -> Option<Box<dyn Clock>> {
+    Some(match key {
+        "beats" => Box::new(BeatsClock),
+        "utc" => Box::new(UtcClock),
+        "greenwich_sidereal" => Box::new(SiderealClock { local: false }),
+        "local_sidereal" => Box::new(SiderealClock { local: true }),
+        "babylonian" => Box::new(SunHoursClock { from_sunset: false }),
+        "italian" => Box::new(SunHoursClock { from_sunset: true }),
+        _ => return None,
+    })
+}
+
+// Readings of the clocks listed in the "clocks" setting, in order; unknown or unreadable clocks are left out
+pub fn read_clocks(now: DateTime<Local>, settings: &ClockSettings) -> Vec<ClockReading> {
+    settings
+        .clocks
+        .as_deref()
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(|key| clock(key.trim()))
+        .filter_map(|clock| {
+            clock.read(now, settings).map(|time| ClockReading { label: clock.label().to_string(), time })
+        })
+        .collect()
+}
@@ -1,7 +1,10 @@
+mod astronomy;
 mod calendar;
 mod calendar_commands;
+mod clocks;
 mod commands;
 mod models;
+mod namedays;
 mod settings;
 mod utils;
 
@@ -36,6 +39,11 @@ pub fn run() {
             settings::verify_password,
             calendar_commands::get_current_dates,
             calendar_commands::get_available_calendar_plugins,
+            calendar_commands::get_clocks,
+            calendar_commands::get_sun_times,
+            calendar_commands::get_zmanim,
+            calendar_commands::get_prayer_times,
+            calendar_commands::get_hebrew_anniversaries,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
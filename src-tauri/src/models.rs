@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -12,10 +13,55 @@ pub struct UserSettings {
     pub auto_lock_enabled: Option<bool>,
     pub auto_lock_minutes: Option<u32>,
     pub show_seconds: Option<bool>,
+    pub show_decimal_time: Option<bool>,
     pub date_format: Option<String>,
     pub theme: Option<String>,
     pub sound_enabled: Option<bool>,
     pub sound_file: Option<String>,
+    pub historical_country: Option<String>,
+    pub historical_dual_dating: Option<bool>,
+    pub historical_new_year: Option<String>,
+    pub roman_show_nundinal_letter: Option<bool>,
+    pub byzantine_greek_months: Option<bool>,
+    pub french_revolutionary_leap_rule: Option<String>,
+    pub jewish_israel_schedule: Option<bool>,
+    pub jewish_learning_cycles: Option<Vec<String>>,
+    pub jewish_yahrzeit_adar: Option<String>,
+    pub jewish_hebrew_date: Option<bool>,
+    pub jewish_hide_thousands: Option<bool>,
+    pub jewish_gematria_punctuation: Option<String>,
+    pub liturgical_tradition: Option<String>,
+    pub name_days: Option<Vec<String>>,
+    pub name_days_calendar: Option<String>,
+    pub people: Option<Vec<Person>>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub elevation: Option<f64>,
+    pub zmanim_candle_lighting_minutes: Option<u32>,
+    pub zmanim_tzeit_minutes: Option<u32>,
+    pub zmanim_mga_minutes: Option<u32>,
+    pub prayer_method: Option<String>,
+    pub prayer_asr: Option<String>,
+    pub prayer_high_latitude: Option<String>,
+    pub prayer_imsak: Option<bool>,
+    pub show_next_prayer: Option<bool>,
+    pub moon_names: Option<String>,
+    pub show_sun_times: Option<bool>,
+    pub zodiac_ayanamsa: Option<String>,
+    pub zodiac_calendar: Option<String>,
+    pub clocks: Option<Vec<String>>,
+}
+
+// Someone whose Hebrew birthday or yahrzeit the lock screen keeps track of; dates are Gregorian "YYYY-MM-DD"
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Person {
+    pub name: String,
+    pub birth_date: Option<String>,
+    pub born_after_sunset: Option<bool>,
+    pub death_date: Option<String>,
+    pub died_after_sunset: Option<bool>,
+    // "male" or "female", for the bar or bat mitzvah date
+    pub gender: Option<String>,
 }
 
 impl Default for UserSettings {
@@ -37,10 +83,43 @@ impl Default for UserSettings {
             auto_lock_enabled: None,
             auto_lock_minutes: None,
             show_seconds: Some(true),
+            show_decimal_time: None,
             date_format: Some("military".to_string()),
             theme: None,
             sound_enabled: None,
             sound_file: None,
+            historical_country: None,
+            historical_dual_dating: None,
+            historical_new_year: None,
+            roman_show_nundinal_letter: None,
+            byzantine_greek_months: None,
+            french_revolutionary_leap_rule: None,
+            jewish_israel_schedule: None,
+            jewish_learning_cycles: None,
+            jewish_yahrzeit_adar: None,
+            jewish_hebrew_date: None,
+            jewish_hide_thousands: None,
+            jewish_gematria_punctuation: None,
+            liturgical_tradition: None,
+            name_days: None,
+            name_days_calendar: None,
+            people: None,
+            latitude: None,
+            longitude: None,
+            elevation: None,
+            zmanim_candle_lighting_minutes: None,
+            zmanim_tzeit_minutes: None,
+            zmanim_mga_minutes: None,
+            prayer_method: None,
+            prayer_asr: None,
+            prayer_high_latitude: None,
+            prayer_imsak: None,
+            show_next_prayer: None,
+            moon_names: None,
+            show_sun_times: None,
+            zodiac_ayanamsa: None,
+            zodiac_calendar: None,
+            clocks: None,
         }
     }
 }
@@ -50,4 +129,81 @@ pub struct CalendarDate {
     pub system: String,
     pub date: String,
     pub additional_info: Option<String>,
-}
\ No newline at end of file
+}
+
+// Halachic times for one civil day; None where the sun never reaches the required depression
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Zmanim {
+    pub alot_hashachar: Option<DateTime<Local>>,
+    pub sunrise: Option<DateTime<Local>>,
+    pub sof_zman_shema_mga: Option<DateTime<Local>>,
+    pub sof_zman_shema_gra: Option<DateTime<Local>>,
+    pub chatzot: DateTime<Local>,
+    pub mincha_gedola: Option<DateTime<Local>>,
+    pub plag_hamincha: Option<DateTime<Local>>,
+    pub candle_lighting: Option<DateTime<Local>>,
+    pub sunset: Option<DateTime<Local>>,
+    pub tzeit_hakochavim: Option<DateTime<Local>>,
+}
+
+// The few settings the alternative clocks read, sent by the lock screen with each poll
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClockSettings {
+    pub clocks: Option<Vec<String>>,
+    pub show_seconds: Option<bool>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub elevation: Option<f64>,
+}
+
+// One alternative clock's label and current reading, e.g. ".beats" and "@523"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClockReading {
+    pub label: String,
+    pub time: String,
+}
+
+// Sunrise, sunset and the three twilights for one civil day; None where the sun never crosses that altitude.
+// polar is "polar_day" or "polar_night" when the sun neither rises nor sets
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SunTimes {
+    pub astronomical_dawn: Option<DateTime<Local>>,
+    pub nautical_dawn: Option<DateTime<Local>>,
+    pub civil_dawn: Option<DateTime<Local>>,
+    pub sunrise: Option<DateTime<Local>>,
+    pub solar_noon: DateTime<Local>,
+    pub sunset: Option<DateTime<Local>>,
+    pub civil_dusk: Option<DateTime<Local>>,
+    pub nautical_dusk: Option<DateTime<Local>>,
+    pub astronomical_dusk: Option<DateTime<Local>>,
+    pub polar: Option<String>,
+    pub day_length_seconds: i64,
+    // Against the day before
+    pub day_length_change_seconds: i64,
+}
+
+// Islamic prayer times for one civil day, with the next prayer to come; None where the sun never reaches
+// the required depression and no high-latitude rule applies
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PrayerTimes {
+    pub method: String,
+    pub imsak: Option<DateTime<Local>>,
+    pub fajr: Option<DateTime<Local>>,
+    pub sunrise: Option<DateTime<Local>>,
+    pub dhuhr: DateTime<Local>,
+    pub asr: Option<DateTime<Local>>,
+    pub maghrib: Option<DateTime<Local>>,
+    pub isha: Option<DateTime<Local>>,
+    pub next_prayer: Option<String>,
+    pub next_prayer_time: Option<DateTime<Local>>,
+}
+
+// One occurrence of a person's Hebrew anniversary; kind is "birthday", "bar_mitzvah", "bat_mitzvah" or "yahrzeit"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HebrewAnniversary {
+    pub name: String,
+    pub kind: String,
+    pub date: NaiveDate,
+    pub hebrew_date: String,
+    pub years: i32,
+}
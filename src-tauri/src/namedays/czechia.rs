@@ -0,0 +1,407 @@
+// czechia.rs
+// Czech name days (jmeniny) from the civil calendar; state holidays have no name
+
+pub const NAME_DAYS: [&[&str]; 12] = [
+    // January
+    &[
+        "",
+        "Karina",
+        "Radmila",
+        "Diana",
+        "Dalimil",
+        "Kašpar, Melichar, Baltazar",
+        "Vilma",
+        "Čestmír",
+        "Vladan",
+        "Břetislav",
+        "Bohdana",
+        "Pravoslav",
+        "Edita",
+        "Radovan",
+        "Alice",
+        "Ctirad",
+        "Drahoslav",
+        "Vladislav",
+        "Doubravka",
+        "Ilona",
+        "Běla",
+        "Slavomír",
+        "Zdeněk",
+        "Milena",
+        "Miloš",
+        "Zora",
+        "Ingrid",
+        "Otýlie",
+        "Zdislava",
+        "Robin",
+        "Marika",
+    ],
+    // February
+    &[
+        "Hynek",
+        "Nela",
+        "Blažej",
+        "Jarmila",
+        "Dobromila",
+        "Vanda",
+        "Veronika",
+        "Milada",
+        "Apolena",
+        "Mojmír",
+        "Božena",
+        "Slavěna",
+        "Věnceslav",
+        "Valentýn",
+        "Jiřina",
+        "Ljuba",
+        "Miloslava",
+        "Gizela",
+        "Patrik",
+        "Oldřich",
+        "Lenka",
+        "Petr",
+        "Svatopluk",
+        "Matěj",
+        "Liliana",
+        "Dorota",
+        "Alexandr",
+        "Lumír",
+        "Horymír",
+    ],
+    // March
+    &[
+        "Bedřich",
+        "Anežka",
+        "Kamil",
+        "Stela",
+        "Kazimír",
+        "Miroslav",
+        "Tomáš",
+        "Gabriela",
+        "Františka",
+        "Viktorie",
+        "Anděla",
+        "Řehoř",
+        "Růžena",
+        "Rút, Matylda",
+        "Ida",
+        "Elena, Herbert",
+        "Vlastimil",
+        "Eduard",
+        "Josef",
+        "Světlana",
+        "Radek",
+        "Leona",
+        "Ivona",
+        "Gabriel",
+        "Marián",
+        "Emanuel",
+        "Dita",
+        "Soňa",
+        "Taťána",
+        "Arnošt",
+        "Kvido",
+    ],
+    // April
+    &[
+        "Hugo",
+        "Erika",
+        "Richard",
+        "Ivana",
+        "Miroslava",
+        "Vendula",
+        "Heřman, Hermína",
+        "Ema",
+        "Dušan",
+        "Darja",
+        "Izabela",
+        "Julius",
+        "Aleš",
+        "Vincenc",
+        "Anastázie",
+        "Irena",
+        "Rudolf",
+        "Valérie",
+        "Rostislav",
+        "Marcela",
+        "Alexandra",
+        "Evženie",
+        "Vojtěch",
+        "Jiří",
+        "Marek",
+        "Oto",
+        "Jaroslav",
+        "Vlastislav",
+        "Robert",
+        "Blahoslav",
+    ],
+    // May
+    &[
+        "",
+        "Zikmund",
+        "Alexej",
+        "Květoslav",
+        "Klaudie",
+        "Radoslav",
+        "Stanislav",
+        "",
+        "Ctibor",
+        "Blažena",
+        "Svatava",
+        "Pankrác",
+        "Servác",
+        "Bonifác",
+        "Žofie",
+        "Přemysl",
+        "Aneta",
+        "Nataša",
+        "Ivo",
+        "Zbyšek",
+        "Monika",
+        "Emil",
+        "Vladimír",
+        "Jana",
+        "Viola",
+        "Filip",
+        "Valdemar",
+        "Vilém",
+        "Maxmilián",
+        "Ferdinand",
+        "Kamila",
+    ],
+    // June
+    &[
+        "Laura",
+        "Jarmil",
+        "Tamara",
+        "Dalibor",
+        "Dobroslav",
+        "Norbert",
+        "Iveta, Slavoj",
+        "Medard",
+        "Stanislava",
+        "Gita",
+        "Bruno",
+        "Antonie",
+        "Antonín",
+        "Roland",
+        "Vít",
+        "Zbyněk",
+        "Adolf",
+        "Milan",
+        "Leoš",
+        "Květa",
+        "Alois",
+        "Pavla",
+        "Zdeňka",
+        "Jan",
+        "Ivan",
+        "Adriana",
+        "Ladislav",
+        "Lubomír",
+        "Petr, Pavel",
+        "Šárka",
+    ],
+    // July
+    &[
+        "Jaroslava",
+        "Patricie",
+        "Radomír",
+        "Prokop",
+        "",
+        "",
+        "Bohuslava",
+        "Nora",
+        "Drahoslava",
+        "Libuše, Amálie",
+        "Olga",
+        "Bořek",
+        "Markéta",
+        "Karolína",
+        "Jindřich",
+        "Luboš",
+        "Martina",
+        "Drahomíra",
+        "Čeněk",
+        "Ilja",
+        "Vítězslav",
+        "Magdaléna",
+        "Libor",
+        "Kristýna",
+        "Jakub",
+        "Anna",
+        "Věroslav",
+        "Viktor",
+        "Marta",
+        "Bořivoj",
+        "Ignác",
+    ],
+    // August
+    &[
+        "Oskar",
+        "Gustav",
+        "Miluše",
+        "Dominik",
+        "Kristián",
+        "Oldřiška",
+        "Lada",
+        "Soběslav",
+        "Roman",
+        "Vavřinec",
+        "Zuzana",
+        "Klára",
+        "Alena",
+        "Alan",
+        "Hana",
+        "Jáchym",
+        "Petra",
+        "Helena",
+        "Ludvík",
+        "Bernard",
+        "Johana",
+        "Bohuslav",
+        "Sandra",
+        "Bartoloměj",
+        "Radim",
+        "Luděk",
+        "Otakar",
+        "Augustýn",
+        "Evelína",
+        "Vladěna",
+        "Pavlína",
+    ],
+    // September
+    &[
+        "Linda, Samuel",
+        "Adéla",
+        "Bronislav",
+        "Jindřiška",
+        "Boris",
+        "Boleslav",
+        "Regína",
+        "Mariana",
+        "Daniela",
+        "Irma",
+        "Denisa",
+        "Marie",
+        "Lubor",
+        "Radka",
+        "Jolana",
+        "Ludmila",
+        "Naděžda",
+        "Kryštof",
+        "Zita",
+        "Oleg",
+        "Matouš",
+        "Darina",
+        "Berta",
+        "Jaromír",
+        "Zlata",
+        "Andrea",
+        "Jonáš",
+        "Václav",
+        "Michal",
+        "Jeroným",
+    ],
+    // October
+    &[
+        "Igor",
+        "Olivie, Oliver",
+        "Bohumil",
+        "František",
+        "Eliška",
+        "Hanuš",
+        "Justýna",
+        "Věra",
+        "Štefan, Sára",
+        "Marina",
+        "Andrej",
+        "Marcel",
+        "Renáta",
+        "Agáta",
+        "Tereza",
+        "Havel",
+        "Hedvika",
+        "Lukáš",
+        "Michaela",
+        "Vendelín",
+        "Brigita",
+        "Sabina",
+        "Teodor",
+        "Nina",
+        "Beáta",
+        "Erik",
+        "Šarlota, Zoe",
+        "",
+        "Silvie",
+        "Tadeáš",
+        "Štěpánka",
+    ],
+    // November
+    &[
+        "Felix",
+        "",
+        "Hubert",
+        "Karel",
+        "Miriam",
+        "Liběna",
+        "Saskie",
+        "Bohumír",
+        "Bohdan",
+        "Evžen",
+        "Martin",
+        "Benedikt",
+        "Tibor",
+        "Sáva",
+        "Leopold",
+        "Otmar",
+        "Mahulena",
+        "Romana",
+        "Alžběta",
+        "Nikola",
+        "Albert",
+        "Cecílie",
+        "Klement",
+        "Emílie",
+        "Kateřina",
+        "Artur",
+        "Xenie",
+        "René",
+        "Zina",
+        "Ondřej",
+    ],
+    // December
+    &[
+        "Iva",
+        "Blanka",
+        "Svatoslav",
+        "Barbora",
+        "Jitka",
+        "Mikuláš",
+        "Ambrož, Benjamín",
+        "Květoslava",
+        "Vratislav",
+        "Julie",
+        "Dana",
+        "Simona",
+        "Lucie",
+        "Lýdie",
+        "Radana, Radan",
+        "Albína",
+        "Daniel",
+        "Miloslav",
+        "Ester",
+        "Dagmar",
+        "Natálie",
+        "Šimon",
+        "Vlasta",
+        "Adam, Eva",
+        "",
+        "Štěpán",
+        "Žaneta",
+        "Bohumila",
+        "Judita",
+        "David",
+        "Silvestr",
+    ],
+];
@@ -0,0 +1,407 @@
+// greece.rs
+// Greek name days (giortes) fixed in the civil year; the moveable ones are worked out from Pascha in mod.rs
+
+pub const NAME_DAYS: [&[&str]; 12] = [
+    // January
+    &[
+        "Vasilios, Vasiliki",
+        "Sylvestros",
+        "Malachias, Gordios",
+        "Theoktistos",
+        "Synklitiki, Theopemptos",
+        "Fotios, Fotini, Theofanis, Iordanis",
+        "Ioannis, Ioanna",
+        "Domniki, Aimilianos",
+        "Polyeuktos, Efstratios",
+        "Dometianos, Markianos",
+        "Theodosios, Theodosia",
+        "Tatiani",
+        "Ermylos, Stratonikos",
+        "Nina",
+        "Pavlos",
+        "Petros",
+        "Antonios, Antonia",
+        "Athanasios, Athanasia, Kyrillos",
+        "Makarios, Markos",
+        "Efthymios, Efthymia",
+        "Maximos, Agni",
+        "Timotheos",
+        "Klimis, Agathangelos",
+        "Xeni",
+        "Grigorios",
+        "Xenofon",
+        "Ioannis",
+        "Efraim",
+        "Ignatios",
+        "Vasilios, Grigorios, Ioannis",
+        "Kyros, Ioannis",
+    ],
+    // February
+    &[
+        "Tryfon",
+        "Ypapanti",
+        "Symeon",
+        "Isidoros",
+        "Agathi",
+        "Fotios, Voukolos",
+        "Parthenios",
+        "Zacharias",
+        "Nikiforos",
+        "Charalambos, Charalambia",
+        "Vlasios",
+        "Meletios",
+        "Martinianos",
+        "Afxentios",
+        "Onisimos",
+        "Pamfilos",
+        "Theodoros, Theodora",
+        "Leon",
+        "Archippos, Filimon",
+        "Leon",
+        "Efstathios, Timotheos",
+        "",
+        "Polykarpos",
+        "Ioannis",
+        "Tarasios",
+        "Porfyrios, Fotini",
+        "Prokopios",
+        "Kyra, Marana",
+        "Kassianos",
+    ],
+    // March
+    &[
+        "Evdokia",
+        "Theodotos",
+        "Evtropios",
+        "Gerasimos",
+        "Konon",
+        "",
+        "",
+        "Theofylaktos",
+        "",
+        "Kodratos",
+        "Sofronios",
+        "Theofanis, Grigorios",
+        "Nikiforos",
+        "Venediktos",
+        "Agapios",
+        "Christodoulos, Christodouli, Savinos",
+        "Alexios",
+        "Kyrillos",
+        "Chrysanthos, Daria",
+        "",
+        "Iakovos",
+        "Vasilios",
+        "Nikon",
+        "Artemon",
+        "Evangelos, Evangelia",
+        "Gavriil, Gavriela",
+        "Matrona",
+        "Ilarion",
+        "Markos, Kyrillos",
+        "Ioannis",
+        "Ypatios",
+    ],
+    // April
+    &[
+        "Maria",
+        "Titos",
+        "Nikitas",
+        "Iosif",
+        "Theodora",
+        "Eftychios",
+        "Kalliopios",
+        "Irodion, Agavos",
+        "Efpsychios",
+        "Terentios",
+        "Antipas",
+        "Vasilios",
+        "Martinos",
+        "",
+        "Aristarchos, Trofimos",
+        "Agapi, Irini, Chionia",
+        "Symeon",
+        "Ioannis",
+        "",
+        "Theodoros",
+        "Alexandra, Ianouarios",
+        "Nathanail, Theodoros",
+        "Georgios, Georgia",
+        "Savvas, Elisavet",
+        "Markos",
+        "Vasilios, Glafyra",
+        "Symeon",
+        "Iason, Sosipatros, Kerkyra",
+        "Memnon",
+        "Iakovos",
+    ],
+    // May
+    &[
+        "Ieremias",
+        "Athanasios",
+        "Timotheos, Mavra",
+        "Pelagia",
+        "Irini",
+        "Iov",
+        "Akakios",
+        "Ioannis, Arsenios",
+        "Isaias, Christoforos",
+        "Simon",
+        "Kyrillos, Methodios",
+        "Epifanios, Germanos",
+        "Glykeria",
+        "Isidoros",
+        "Pachomios, Achillios",
+        "Theodoros",
+        "Andronikos, Iounia",
+        "Theodotos, Petros",
+        "Patrikios",
+        "Thalelaios",
+        "Konstantinos, Eleni",
+        "Vasiliskos",
+        "Michail",
+        "Symeon",
+        "Ioannis",
+        "Karpos",
+        "Therapon",
+        "Nikitas",
+        "Theodosia",
+        "Isaakios",
+        "Ermias",
+    ],
+    // June
+    &[
+        "Ioustinos",
+        "Nikiforos",
+        "Loukillianos",
+        "Mitrofanis",
+        "Dorotheos",
+        "Vissarion",
+        "Theodotos",
+        "Theodoros",
+        "Kyrillos",
+        "Timotheos",
+        "Varnavas, Vartholomaios",
+        "Onoufrios, Petros",
+        "Akylina",
+        "Elissaios, Methodios",
+        "Amos",
+        "Tychon",
+        "Manouil",
+        "Leontios",
+        "Paisios",
+        "Methodios",
+        "Ioulianos",
+        "Evsevios",
+        "Agrippini",
+        "Prodromos",
+        "Fevronia",
+        "David",
+        "Sampson",
+        "Kyros, Ioannis",
+        "Petros, Pavlos",
+        "Apostolos",
+    ],
+    // July
+    &[
+        "Anargyros",
+        "",
+        "Yakinthos, Anatolios",
+        "Andreas",
+        "Athanasios",
+        "Sisois",
+        "Kyriaki",
+        "Prokopios",
+        "Pangratios",
+        "Antonios",
+        "Efimia, Olga",
+        "Proklos",
+        "Gavriil",
+        "Akylas",
+        "Kirykos, Ioulitta",
+        "Athinogenis",
+        "Marina",
+        "Aimilianos, Yakinthos",
+        "Makrina, Dios",
+        "Ilias",
+        "Symeon",
+        "Magdalini",
+        "Trofimos, Theofilos",
+        "Christina",
+        "Anna",
+        "Paraskevi",
+        "Panteleimon",
+        "Prochoros, Nikanor",
+        "Kallinikos",
+        "Silas, Silouanos",
+        "Evdokimos",
+    ],
+    // August
+    &[
+        "Eleazaros, Solomoni",
+        "Stefanos",
+        "Isaakios, Dalmatos",
+        "Maximilianos, Iamvlichos",
+        "Efsignios",
+        "Sotirios, Sotiria",
+        "Dometios",
+        "Aimilianos",
+        "Matthias",
+        "Lavrentios",
+        "Evplos",
+        "Fotios, Aniketos",
+        "Maximos",
+        "Michaias",
+        "Maria, Panagiotis, Panagiota, Despina",
+        "Diomidis",
+        "Myron",
+        "Floros, Lavros",
+        "Andreas",
+        "Samouil",
+        "Thaddaios, Vassa",
+        "Agathonikos",
+        "Irinaios",
+        "Kosmas, Eftychis",
+        "Titos, Vartholomaios",
+        "Adrianos, Natalia",
+        "Fanourios, Poimin",
+        "Moysis",
+        "Ioannis",
+        "Alexandros, Alexandra",
+        "",
+    ],
+    // September
+    &[
+        "Symeon",
+        "Mamas",
+        "Anthimos, Theoktistos",
+        "Vavylas, Moysis, Ermioni",
+        "Zacharias, Elisavet",
+        "Evdoxios",
+        "Sozon",
+        "Maria",
+        "Ioakeim",
+        "Minodora, Mitrodora, Nymfodora",
+        "Theodora",
+        "Avtonomos",
+        "Kornilios",
+        "Stavros, Stavroula",
+        "Nikitas",
+        "Efimia",
+        "Sofia, Pisti, Elpida, Agapi",
+        "Evmenios, Ariadni",
+        "Trofimos",
+        "Efstathios",
+        "Kodratos",
+        "Fokas, Ionas",
+        "Xanthippi, Polyxeni",
+        "Thekla, Silouanos",
+        "Efrosyni",
+        "Ioannis",
+        "Kallistratos",
+        "Chariton",
+        "Kyriakos",
+        "Grigorios",
+    ],
+    // October
+    &[
+        "Romanos",
+        "Kyprianos, Ioustini",
+        "Dionysios",
+        "Ierotheos",
+        "Charitini",
+        "Thomas",
+        "Sergios, Vakchos",
+        "Pelagia",
+        "Iakovos",
+        "Evlampios, Evlampia",
+        "Filippos, Theofanis",
+        "Provos, Tarachos, Andronikos",
+        "Karpos, Papylos",
+        "Nazarios",
+        "Loukianos, Efthymios",
+        "Longinos",
+        "Andreas",
+        "Loukas",
+        "Ioil",
+        "Artemios",
+        "Ilarion",
+        "Avergios",
+        "Iakovos",
+        "Arethas",
+        "Markianos, Martyrios",
+        "Dimitrios, Dimitra",
+        "Nestor",
+        "Terentios, Neonilla",
+        "Anastasia, Avramios",
+        "Zinovios, Zinovia",
+        "Stachys",
+    ],
+    // November
+    &[
+        "Kosmas, Damianos, Anargyros",
+        "Akindynos",
+        "Georgios",
+        "Ioannikios",
+        "Galaktion, Epistimi",
+        "Pavlos",
+        "Ieron",
+        "Michail, Gavriil, Angelos, Angeliki",
+        "Nektarios",
+        "Orestis, Erastos",
+        "Minas",
+        "Nilos",
+        "Ioannis",
+        "Filippos",
+        "Gourias, Samonas, Avivos",
+        "Matthaios",
+        "Grigorios",
+        "Platon, Romanos",
+        "Avdias, Varlaam",
+        "Grigorios, Proklos",
+        "Maria",
+        "Filimon, Kaikilia",
+        "Amfilochios",
+        "Klimis, Petros",
+        "Aikaterini",
+        "Stylianos, Styliani, Alypios",
+        "Iakovos",
+        "Stefanos",
+        "Paramonos",
+        "Andreas",
+    ],
+    // December
+    &[
+        "Naoum",
+        "Avvakoum",
+        "Sofonias",
+        "Varvara",
+        "Savvas",
+        "Nikolaos, Nikoleta",
+        "Amvrosios",
+        "Patapios",
+        "Anna",
+        "Minas, Ermogenis, Evgrafos",
+        "Daniil",
+        "Spyridon",
+        "Efstratios, Loukia",
+        "Thyrsos",
+        "Eleftherios, Eleftheria",
+        "Theofano, Angaios",
+        "Daniil",
+        "Sevastianos",
+        "Vonifatios",
+        "Ignatios",
+        "Ioulianna",
+        "Anastasia",
+        "Theodoulos",
+        "Evgenia",
+        "Christos, Christina, Emmanouil",
+        "",
+        "Stefanos",
+        "Domna",
+        "Markellos",
+        "Anysia",
+        "Melani",
+    ],
+];
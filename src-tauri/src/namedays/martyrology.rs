@@ -0,0 +1,407 @@
+// martyrology.rs
+// Saints of the Roman Martyrology, one or two of those it commemorates on each day of the year
+
+pub const SAINTS: [&[&str]; 12] = [
+    // January
+    &[
+        "Mary, Mother of God",
+        "Basil the Great and Gregory Nazianzen",
+        "Genevieve",
+        "Elizabeth Ann Seton",
+        "John Neumann; Simeon Stylites",
+        "Epiphany of the Lord",
+        "Raymond of Penyafort",
+        "Severinus of Noricum",
+        "Adrian of Canterbury",
+        "Gregory of Nyssa",
+        "Theodosius the Cenobiarch",
+        "Marguerite Bourgeoys",
+        "Hilary of Poitiers",
+        "Felix of Nola",
+        "Paul the Hermit; Maurus",
+        "Marcellus I; Berard and Companions",
+        "Anthony, Abbot",
+        "Margaret of Hungary",
+        "Wulfstan of Worcester",
+        "Fabian; Sebastian",
+        "Agnes",
+        "Vincent, Deacon",
+        "Ildephonsus of Toledo",
+        "Francis de Sales",
+        "Conversion of St Paul",
+        "Timothy and Titus",
+        "Angela Merici",
+        "Thomas Aquinas",
+        "Gildas the Wise",
+        "Hyacintha Mariscotti",
+        "John Bosco",
+    ],
+    // February
+    &[
+        "Brigid of Kildare",
+        "Presentation of the Lord",
+        "Blaise; Ansgar",
+        "Joan of France",
+        "Agatha",
+        "Paul Miki and Companions",
+        "Moses, Bishop",
+        "Jerome Emiliani; Josephine Bakhita",
+        "Apollonia",
+        "Scholastica",
+        "Our Lady of Lourdes",
+        "Meletius of Antioch",
+        "Catherine de' Ricci",
+        "Cyril and Methodius; Valentine",
+        "Claude de la Colombière",
+        "Juliana of Nicomedia",
+        "Seven Holy Founders of the Servite Order",
+        "Simeon of Jerusalem",
+        "Conrad of Piacenza",
+        "Francisco and Jacinta Marto",
+        "Peter Damian",
+        "Chair of St Peter",
+        "Polycarp",
+        "Ethelbert of Kent",
+        "Walburga",
+        "Porphyrius of Gaza",
+        "Gabriel of Our Lady of Sorrows",
+        "Romanus of Condat",
+        "Oswald of Worcester",
+    ],
+    // March
+    &[
+        "David of Wales",
+        "Chad of Mercia",
+        "Katharine Drexel",
+        "Casimir",
+        "John Joseph of the Cross",
+        "Colette",
+        "Perpetua and Felicity",
+        "John of God",
+        "Frances of Rome",
+        "John Ogilvie",
+        "Eulogius of Córdoba",
+        "Maximilian of Tebessa",
+        "Leander of Seville",
+        "Matilda",
+        "Louise de Marillac; Clement Mary Hofbauer",
+        "Heribert of Cologne",
+        "Patrick",
+        "Cyril of Jerusalem",
+        "Joseph, Spouse of the Blessed Virgin Mary",
+        "Cuthbert",
+        "Nicholas of Flüe",
+        "Lea of Rome",
+        "Turibius of Mogrovejo",
+        "Catherine of Sweden",
+        "Annunciation of the Lord",
+        "Ludger",
+        "Rupert of Salzburg",
+        "Guntram",
+        "Jonas and Barachisius",
+        "John Climacus",
+        "Benjamin, Deacon",
+    ],
+    // April
+    &[
+        "Hugh of Grenoble",
+        "Francis of Paola",
+        "Richard of Chichester",
+        "Isidore",
+        "Vincent Ferrer",
+        "William of Æbelholt",
+        "John Baptist de la Salle",
+        "Julie Billiart",
+        "Waltrude",
+        "Fulbert of Chartres",
+        "Stanislaus",
+        "Julius I; Zeno of Verona",
+        "Martin I",
+        "Lidwina",
+        "Damien of Molokai",
+        "Bernadette Soubirous; Benedict Joseph Labre",
+        "Anicetus",
+        "Galdinus of Milan",
+        "Leo IX",
+        "Agnes of Montepulciano",
+        "Anselm",
+        "Caius",
+        "George; Adalbert",
+        "Fidelis of Sigmaringen",
+        "Mark, Evangelist",
+        "Cletus",
+        "Zita",
+        "Peter Chanel; Louis Grignion de Montfort",
+        "Catherine of Siena",
+        "Pius V",
+    ],
+    // May
+    &[
+        "Joseph the Worker",
+        "Athanasius",
+        "Philip and James, Apostles",
+        "Florian",
+        "Hilary of Arles",
+        "François de Laval",
+        "John of Beverley",
+        "Peter of Tarentaise",
+        "Pachomius",
+        "John of Ávila",
+        "Mamertus of Vienne",
+        "Nereus and Achilleus; Pancras",
+        "Our Lady of Fátima",
+        "Matthias, Apostle",
+        "Isidore the Farmer",
+        "Brendan the Navigator; Simon Stock",
+        "Paschal Baylon",
+        "John I",
+        "Celestine V; Ivo of Kermartin",
+        "Bernardine of Siena",
+        "Christopher Magallanes and Companions",
+        "Rita of Cascia",
+        "John Baptist de Rossi",
+        "Vincent of Lérins",
+        "Bede the Venerable; Gregory VII; Mary Magdalene de' Pazzi",
+        "Philip Neri",
+        "Augustine of Canterbury",
+        "Germanus of Paris",
+        "Maximinus of Trier",
+        "Joan of Arc; Ferdinand III of Castile",
+        "Visitation of the Blessed Virgin Mary",
+    ],
+    // June
+    &[
+        "Justin Martyr",
+        "Marcellinus and Peter; Erasmus",
+        "Charles Lwanga and Companions",
+        "Francis Caracciolo",
+        "Boniface",
+        "Norbert",
+        "Robert of Newminster",
+        "Medard",
+        "Ephrem; Columba",
+        "Landry of Paris",
+        "Barnabas, Apostle",
+        "Onuphrius",
+        "Anthony of Padua",
+        "Methodius of Constantinople",
+        "Vitus",
+        "Lutgardis",
+        "Rainerius of Pisa",
+        "Gregory Barbarigo",
+        "Romuald; Juliana Falconieri",
+        "Silverius",
+        "Aloysius Gonzaga",
+        "Paulinus of Nola; John Fisher and Thomas More",
+        "Etheldreda",
+        "Nativity of St John the Baptist",
+        "William of Vercelli",
+        "John and Paul, Martyrs",
+        "Cyril of Alexandria",
+        "Irenaeus",
+        "Peter and Paul, Apostles",
+        "First Martyrs of the Holy Roman Church",
+    ],
+    // July
+    &[
+        "Oliver Plunkett",
+        "Bernardino Realino",
+        "Thomas, Apostle",
+        "Elizabeth of Portugal",
+        "Anthony Zaccaria",
+        "Maria Goretti",
+        "Willibald",
+        "Kilian",
+        "Augustine Zhao Rong and Companions; Veronica Giuliani",
+        "Rufina and Secunda",
+        "Benedict",
+        "John Gualbert",
+        "Henry",
+        "Camillus de Lellis",
+        "Bonaventure",
+        "Our Lady of Mount Carmel",
+        "Marcellina",
+        "Arnulf of Metz",
+        "Macrina the Younger",
+        "Apollinaris; Margaret of Antioch",
+        "Lawrence of Brindisi",
+        "Mary Magdalene",
+        "Bridget of Sweden",
+        "Sharbel Makhluf; Christina",
+        "James, Apostle",
+        "Joachim and Anne",
+        "Pantaleon",
+        "Nazarius and Celsus; Victor I",
+        "Martha, Mary and Lazarus",
+        "Peter Chrysologus",
+        "Ignatius of Loyola",
+    ],
+    // August
+    &[
+        "Alphonsus Liguori",
+        "Eusebius of Vercelli; Peter Julian Eymard",
+        "Lydia",
+        "John Vianney",
+        "Dedication of the Basilica of St Mary Major",
+        "Transfiguration of the Lord",
+        "Sixtus II and Companions; Cajetan",
+        "Dominic",
+        "Teresa Benedicta of the Cross",
+        "Lawrence",
+        "Clare",
+        "Jane Frances de Chantal",
+        "Pontian and Hippolytus",
+        "Maximilian Kolbe",
+        "Assumption of the Blessed Virgin Mary",
+        "Stephen of Hungary; Roch",
+        "Clare of Montefalco",
+        "Helena",
+        "John Eudes",
+        "Bernard",
+        "Pius X",
+        "Queenship of the Blessed Virgin Mary",
+        "Rose of Lima",
+        "Bartholomew, Apostle",
+        "Louis; Joseph Calasanz",
+        "Teresa of Jesus Jornet",
+        "Monica",
+        "Augustine",
+        "Passion of St John the Baptist",
+        "Fiacre",
+        "Aidan of Lindisfarne; Raymond Nonnatus",
+    ],
+    // September
+    &[
+        "Giles",
+        "Brocard",
+        "Gregory the Great",
+        "Rosalia",
+        "Teresa of Calcutta",
+        "Magnus of Füssen",
+        "Regina",
+        "Nativity of the Blessed Virgin Mary",
+        "Peter Claver",
+        "Nicholas of Tolentino",
+        "Protus and Hyacinth",
+        "Most Holy Name of Mary",
+        "John Chrysostom",
+        "Exaltation of the Holy Cross",
+        "Our Lady of Sorrows",
+        "Cornelius and Cyprian",
+        "Robert Bellarmine; Hildegard of Bingen",
+        "Joseph of Cupertino",
+        "Januarius",
+        "Andrew Kim Tae-gon, Paul Chong Ha-sang and Companions",
+        "Matthew, Apostle and Evangelist",
+        "Maurice and Companions",
+        "Pius of Pietrelcina",
+        "Gerard Sagredo",
+        "Sergius of Radonezh",
+        "Cosmas and Damian",
+        "Vincent de Paul",
+        "Wenceslaus; Lawrence Ruiz and Companions",
+        "Michael, Gabriel and Raphael, Archangels",
+        "Jerome",
+    ],
+    // October
+    &[
+        "Thérèse of the Child Jesus",
+        "Guardian Angels",
+        "Gerard of Brogne",
+        "Francis of Assisi",
+        "Faustina Kowalska",
+        "Bruno",
+        "Our Lady of the Rosary",
+        "Pelagia",
+        "Denis and Companions; John Leonardi",
+        "Daniel Comboni",
+        "Bruno of Cologne",
+        "Wilfrid",
+        "Theophilus of Antioch",
+        "Callistus I",
+        "Teresa of Jesus",
+        "Hedwig; Margaret Mary Alacoque",
+        "Ignatius of Antioch",
+        "Luke, Evangelist",
+        "John de Brébeuf, Isaac Jogues and Companions; Paul of the Cross",
+        "Maria Bertilla Boscardin",
+        "Hilarion",
+        "Donatus of Fiesole",
+        "John of Capistrano",
+        "Anthony Mary Claret",
+        "Crispin and Crispinian",
+        "Evaristus",
+        "Frumentius",
+        "Simon and Jude, Apostles",
+        "Narcissus of Jerusalem",
+        "Marcellus the Centurion",
+        "Wolfgang; Alphonsus Rodriguez",
+    ],
+    // November
+    &[
+        "All Saints",
+        "All Souls",
+        "Martin de Porres; Hubert",
+        "Charles Borromeo",
+        "Zechariah and Elizabeth",
+        "Leonard of Noblac",
+        "Willibrord",
+        "Four Crowned Martyrs",
+        "Dedication of the Lateran Basilica",
+        "Leo the Great",
+        "Martin of Tours",
+        "Josaphat",
+        "Homobonus",
+        "Lawrence O'Toole",
+        "Albert the Great",
+        "Margaret of Scotland; Gertrude",
+        "Elizabeth of Hungary",
+        "Dedication of the Basilicas of Sts Peter and Paul; Rose Philippine Duchesne",
+        "Mechtilde of Hackeborn",
+        "Edmund the Martyr",
+        "Presentation of the Blessed Virgin Mary",
+        "Cecilia",
+        "Clement I; Columban",
+        "Andrew Dũng-Lạc and Companions",
+        "Catherine of Alexandria",
+        "Leonard of Port Maurice",
+        "Virgil of Salzburg",
+        "James of the Marches",
+        "Saturninus of Toulouse",
+        "Andrew, Apostle",
+    ],
+    // December
+    &[
+        "Eligius",
+        "Chromatius of Aquileia",
+        "Francis Xavier",
+        "John Damascene; Barbara",
+        "Sabbas",
+        "Nicholas",
+        "Ambrose",
+        "Immaculate Conception of the Blessed Virgin Mary",
+        "Juan Diego",
+        "Eulalia of Mérida",
+        "Damasus I",
+        "Our Lady of Guadalupe",
+        "Lucy",
+        "John of the Cross",
+        "Mary Crucified Di Rosa",
+        "Adelaide",
+        "Olympias",
+        "Gatian of Tours",
+        "Anastasius I",
+        "Dominic of Silos",
+        "Peter Canisius",
+        "Frances Xavier Cabrini",
+        "John of Kanty",
+        "Tharsilla",
+        "Nativity of the Lord",
+        "Stephen, First Martyr",
+        "John, Apostle and Evangelist",
+        "Holy Innocents",
+        "Thomas Becket",
+        "Egwin of Worcester",
+        "Sylvester I",
+    ],
+];
@@ -0,0 +1,407 @@
+// menaion.rs
+// Principal commemorations of the Orthodox menaion, by Julian (Old Style) date
+
+pub const SAINTS: [&[&str]; 12] = [
+    // January
+    &[
+        "Circumcision of the Lord; Basil the Great",
+        "Sylvester, Pope of Rome; Seraphim of Sarov",
+        "Prophet Malachi; Martyr Gordius",
+        "Synaxis of the Seventy Apostles",
+        "Martyrs Theopemptus and Theonas; Eve of Theophany",
+        "Theophany of the Lord",
+        "Synaxis of John the Baptist",
+        "George the Chozebite; Domnica",
+        "Martyr Polyeuctus; Philip of Moscow",
+        "Gregory of Nyssa; Dometian of Melitene",
+        "Theodosius the Great",
+        "Martyr Tatiana",
+        "Martyrs Hermylus and Stratonicus",
+        "Holy Fathers slain at Sinai and Raithu; Nina of Georgia",
+        "Paul of Thebes; John the Hut-dweller",
+        "Veneration of the Chains of the Apostle Peter",
+        "Anthony the Great",
+        "Athanasius and Cyril of Alexandria",
+        "Macarius the Great; Mark of Ephesus",
+        "Euthymius the Great",
+        "Maximus the Confessor",
+        "Apostle Timothy; Anastasius the Persian",
+        "Hieromartyr Clement of Ancyra; Martyr Agathangelus",
+        "Xenia of Rome; Xenia of St Petersburg",
+        "Gregory the Theologian",
+        "Xenophon and Mary",
+        "Translation of the relics of John Chrysostom",
+        "Ephraim the Syrian",
+        "Translation of the relics of Ignatius of Antioch",
+        "Three Holy Hierarchs",
+        "Unmercenaries Cyrus and John",
+    ],
+    // February
+    &[
+        "Martyr Tryphon",
+        "Meeting of the Lord",
+        "Simeon the God-receiver and Anna the Prophetess",
+        "Isidore of Pelusium",
+        "Martyr Agatha",
+        "Photius the Great; Bucolus of Smyrna",
+        "Parthenius of Lampsacus",
+        "Great-martyr Theodore Stratelates; Prophet Zechariah",
+        "Martyr Nicephorus",
+        "Hieromartyr Charalambos",
+        "Hieromartyr Blaise of Sebaste",
+        "Meletius of Antioch; Alexis of Moscow",
+        "Martinian",
+        "Auxentius; Cyril, Equal-to-the-Apostles",
+        "Apostle Onesimus",
+        "Martyr Pamphilus and Companions",
+        "Great-martyr Theodore the Recruit",
+        "Leo the Great, Pope of Rome",
+        "Apostles Archippus and Philemon",
+        "Leo of Catania",
+        "Timothy of Symbola; Eustathius of Antioch",
+        "Finding of the relics of the martyrs at Eugenius",
+        "Hieromartyr Polycarp of Smyrna",
+        "First and Second Finding of the Head of John the Baptist",
+        "Tarasius of Constantinople",
+        "Porphyrius of Gaza",
+        "Procopius of Decapolis",
+        "Basil the Confessor",
+        "John Cassian",
+    ],
+    // March
+    &[
+        "Martyr Eudokia",
+        "Hieromartyr Theodotus of Cyrenia",
+        "Martyrs Eutropius, Cleonicus and Basiliscus",
+        "Gerasimus of the Jordan",
+        "Martyr Conon",
+        "Forty-two Martyrs of Amorium",
+        "Hieromartyrs of Cherson",
+        "Theophylact of Nicomedia",
+        "Forty Martyrs of Sebaste",
+        "Martyr Quadratus",
+        "Sophronius of Jerusalem",
+        "Theophanes the Confessor; Gregory the Dialogist",
+        "Translation of the relics of Nicephorus of Constantinople",
+        "Benedict of Nursia",
+        "Martyr Agapius",
+        "Martyr Sabinus",
+        "Alexis the Man of God",
+        "Cyril of Jerusalem",
+        "Martyrs Chrysanthus and Daria",
+        "Fathers slain at the Monastery of St Sabbas",
+        "James the Confessor",
+        "Hieromartyr Basil of Ancyra",
+        "Hieromartyr Nikon",
+        "Forefeast of the Annunciation",
+        "Annunciation of the Theotokos",
+        "Synaxis of the Archangel Gabriel",
+        "Matrona of Thessalonica",
+        "Hilarion the New",
+        "Mark of Arethusa and Cyril the Deacon",
+        "John Climacus",
+        "Hypatius of Gangra",
+    ],
+    // April
+    &[
+        "Mary of Egypt",
+        "Titus the Wonderworker",
+        "Nicetas the Confessor",
+        "Joseph the Hymnographer",
+        "Martyrs Claudius, Diodorus and Companions",
+        "Eutychius of Constantinople",
+        "Martyr Calliopus",
+        "Apostles Herodion, Agabus and Companions",
+        "Martyr Eupsychius",
+        "Martyrs Terence, Pompeius and Companions",
+        "Hieromartyr Antipas of Pergamum",
+        "Basil of Parium",
+        "Martin the Confessor, Pope of Rome",
+        "Martyrs Anthony, John and Eustathius of Vilnius",
+        "Apostles Aristarchus, Pudens and Trophimus",
+        "Martyrs Agape, Irene and Chionia",
+        "Simeon of Persia",
+        "John, disciple of Gregory of Decapolis",
+        "John the Ancient of the Caves",
+        "Theodore Trichinas",
+        "Hieromartyr Januarius",
+        "Theodore of Sykeon",
+        "Great-martyr George",
+        "Martyr Sabbas Stratelates",
+        "Apostle and Evangelist Mark",
+        "Hieromartyr Basil of Amasea",
+        "Hieromartyr Simeon, kinsman of the Lord",
+        "Apostles Jason and Sosipater",
+        "Nine Martyrs of Cyzicus",
+        "Apostle James, son of Zebedee",
+    ],
+    // May
+    &[
+        "Prophet Jeremiah",
+        "Athanasius the Great",
+        "Martyrs Timothy and Maura; Theodosius of the Kiev Caves",
+        "Martyr Pelagia of Tarsus",
+        "Great-martyr Irene",
+        "Righteous Job",
+        "Appearance of the Sign of the Cross over Jerusalem",
+        "Apostle and Evangelist John the Theologian",
+        "Prophet Isaiah; Translation of the relics of Nicholas the Wonderworker",
+        "Apostle Simon the Zealot",
+        "Cyril and Methodius, Equal-to-the-Apostles",
+        "Epiphanius of Cyprus; Germanus of Constantinople",
+        "Martyr Glyceria",
+        "Martyr Isidore of Chios",
+        "Pachomius the Great",
+        "Theodore the Sanctified",
+        "Apostles Andronicus and Junia",
+        "Martyrs Theodotus, Peter and Companions",
+        "Hieromartyr Patrick of Prusa",
+        "Martyr Thalelaeus",
+        "Constantine and Helen, Equal-to-the-Apostles",
+        "Martyr Basiliscus",
+        "Michael the Confessor",
+        "Simeon the Stylite of the Wonderful Mountain",
+        "Third Finding of the Head of John the Baptist",
+        "Apostle Carpus",
+        "Hieromartyr Therapont",
+        "Nicetas of Chalcedon",
+        "Martyr Theodosia",
+        "Isaac of Dalmatia",
+        "Apostle Hermas",
+    ],
+    // June
+    &[
+        "Martyr Justin the Philosopher",
+        "Nicephorus of Constantinople",
+        "Martyr Lucillian",
+        "Metrophanes of Constantinople",
+        "Hieromartyr Dorotheus of Tyre",
+        "Bessarion of Egypt",
+        "Hieromartyr Theodotus of Ancyra",
+        "Translation of the relics of Theodore Stratelates",
+        "Cyril of Alexandria",
+        "Hieromartyr Timothy of Prusa",
+        "Apostles Bartholomew and Barnabas",
+        "Onuphrius the Great; Peter of Athos",
+        "Martyr Aquilina",
+        "Prophet Elisha; Methodius of Constantinople",
+        "Prophet Amos",
+        "Tikhon of Amathus",
+        "Martyrs Manuel, Sabel and Ismael",
+        "Martyr Leontius",
+        "Apostle Jude, brother of the Lord",
+        "Hieromartyr Methodius of Patara",
+        "Martyr Julian of Tarsus",
+        "Hieromartyr Eusebius of Samosata",
+        "Martyr Agrippina",
+        "Nativity of John the Baptist",
+        "Martyr Febronia",
+        "David of Thessalonica",
+        "Sampson the Hospitable",
+        "Translation of the relics of Cyrus and John",
+        "Apostles Peter and Paul",
+        "Synaxis of the Twelve Apostles",
+    ],
+    // July
+    &[
+        "Unmercenaries Cosmas and Damian of Rome",
+        "Deposition of the Robe of the Theotokos",
+        "Martyr Hyacinth",
+        "Andrew of Crete",
+        "Athanasius of Athos",
+        "Sisoes the Great",
+        "Thomas of Maleon; Great-martyr Kyriaki",
+        "Great-martyr Procopius",
+        "Hieromartyr Pancratius",
+        "Forty-five Martyrs of Nicopolis; Anthony of the Kiev Caves",
+        "Great-martyr Euphemia; Olga, Equal-to-the-Apostles",
+        "Martyrs Proclus and Hilary",
+        "Synaxis of the Archangel Gabriel",
+        "Apostle Aquila",
+        "Vladimir, Equal-to-the-Apostles",
+        "Hieromartyr Athenogenes",
+        "Great-martyr Marina",
+        "Martyrs Hyacinth of Amastris and Emilian",
+        "Macrina; Dius",
+        "Prophet Elijah",
+        "Simeon the Fool-for-Christ and John",
+        "Mary Magdalene, Equal-to-the-Apostles",
+        "Martyrs Trophimus, Theophilus and Companions",
+        "Great-martyr Christina; Boris and Gleb",
+        "Dormition of Anna",
+        "Hieromartyr Hermolaus; Martyr Paraskevi",
+        "Great-martyr Panteleimon",
+        "Apostles Prochorus, Nicanor, Timon and Parmenas",
+        "Martyr Callinicus",
+        "Apostles Silas and Silvanus",
+        "Forefeast of the Procession of the Cross; Eudokimos",
+    ],
+    // August
+    &[
+        "Procession of the Cross; Seven Maccabee Martyrs",
+        "Translation of the relics of Stephen the Protomartyr",
+        "Isaac, Dalmatus and Faustus",
+        "Seven Youths of Ephesus",
+        "Martyr Eusignius",
+        "Transfiguration of the Lord",
+        "Martyr Dometius",
+        "Emilian the Confessor",
+        "Apostle Matthias",
+        "Archdeacon Lawrence",
+        "Martyr Euplus",
+        "Martyrs Photius and Anicetas",
+        "Tikhon of Zadonsk; Translation of the relics of Maximus the Confessor",
+        "Prophet Micah",
+        "Dormition of the Theotokos",
+        "Image of Christ Not-Made-by-Hands",
+        "Martyr Myron",
+        "Martyrs Florus and Laurus",
+        "Great-martyr Andrew Stratelates",
+        "Prophet Samuel",
+        "Apostle Thaddeus; Martyr Bassa",
+        "Martyr Agathonicus",
+        "Martyr Lupus; Irenaeus of Lyons",
+        "Hieromartyr Eutyches; Cosmas of Aetolia",
+        "Return of the relics of the Apostle Bartholomew; Apostle Titus",
+        "Martyrs Adrian and Natalia",
+        "Pimen the Great",
+        "Moses the Black",
+        "Beheading of John the Baptist",
+        "Alexander, John and Paul of Constantinople; Translation of the relics of Alexander Nevsky",
+        "Deposition of the Sash of the Theotokos",
+    ],
+    // September
+    &[
+        "Church New Year; Symeon the Stylite",
+        "Martyr Mamas; John the Faster",
+        "Hieromartyr Anthimus of Nicomedia",
+        "Hieromartyr Babylas; Prophet Moses",
+        "Prophet Zechariah and Righteous Elizabeth",
+        "Miracle of the Archangel Michael at Colossae",
+        "Martyr Sozon",
+        "Nativity of the Theotokos",
+        "Joachim and Anna",
+        "Martyrs Menodora, Metrodora and Nymphodora",
+        "Theodora of Alexandria",
+        "Hieromartyr Autonomus",
+        "Dedication of the Church of the Resurrection",
+        "Exaltation of the Cross",
+        "Great-martyr Nicetas",
+        "Great-martyr Euphemia",
+        "Martyrs Sophia, Faith, Hope and Love",
+        "Eumenius of Gortyna",
+        "Martyrs Trophimus, Sabbatius and Dorymedon",
+        "Great-martyr Eustathius",
+        "Apostle Quadratus; Finding of the relics of Demetrius of Rostov",
+        "Hieromartyr Phocas; Prophet Jonah",
+        "Conception of John the Baptist",
+        "Protomartyr Thecla; Silouan of Athos",
+        "Euphrosyne of Alexandria; Sergius of Radonezh",
+        "Repose of the Apostle John the Theologian",
+        "Martyr Callistratus",
+        "Chariton the Confessor",
+        "Cyriacus the Anchorite",
+        "Hieromartyr Gregory the Illuminator",
+    ],
+    // October
+    &[
+        "Protection of the Theotokos; Romanos the Melodist",
+        "Hieromartyr Cyprian and Martyr Justina",
+        "Hieromartyr Dionysius the Areopagite",
+        "Hierotheus of Athens",
+        "Martyr Charitina",
+        "Apostle Thomas",
+        "Martyrs Sergius and Bacchus",
+        "Pelagia the Penitent",
+        "Apostle James, son of Alphaeus",
+        "Martyrs Eulampius and Eulampia",
+        "Apostle Philip the Deacon; Theophanes the Hymnographer",
+        "Martyrs Probus, Tarachus and Andronicus",
+        "Martyrs Carpus and Papylus",
+        "Martyrs Nazarius, Gervasius, Protasius and Celsus; Paraskeva of Serbia",
+        "Euthymius the New; Hieromartyr Lucian of Antioch",
+        "Martyr Longinus the Centurion",
+        "Prophet Hosea; Martyr Andrew of Crete",
+        "Apostle and Evangelist Luke",
+        "Prophet Joel; John of Rila",
+        "Great-martyr Artemius",
+        "Hilarion the Great",
+        "Abercius of Hierapolis, Equal-to-the-Apostles",
+        "Apostle James, brother of the Lord",
+        "Martyr Arethas",
+        "Martyrs Marcian and Martyrius",
+        "Great-martyr Demetrius of Thessalonica",
+        "Martyr Nestor",
+        "Martyrs Terence and Neonilla; Paraskevi of Iconium",
+        "Martyr Anastasia the Roman; Abramius the Recluse",
+        "Hieromartyr Zenobius and Zenobia",
+        "Apostles Stachys, Amplias and Companions",
+    ],
+    // November
+    &[
+        "Unmercenaries Cosmas and Damian of Asia",
+        "Martyrs Acindynus, Pegasius and Companions",
+        "Hieromartyrs Acepsimas, Joseph and Aithalas",
+        "Joannicius the Great",
+        "Martyrs Galaction and Episteme",
+        "Paul the Confessor of Constantinople",
+        "Thirty-three Martyrs of Melitene",
+        "Synaxis of the Archangel Michael and the Bodiless Powers",
+        "Matrona of Constantinople; Nectarius of Aegina",
+        "Apostles Erastus, Olympas and Companions",
+        "Great-martyr Menas; Theodore the Studite",
+        "John the Merciful",
+        "John Chrysostom",
+        "Apostle Philip",
+        "Martyrs Gurias, Samonas and Abibus",
+        "Apostle and Evangelist Matthew",
+        "Gregory the Wonderworker",
+        "Martyrs Plato and Roman",
+        "Prophet Obadiah; Martyr Barlaam",
+        "Gregory of Decapolis; Proclus of Constantinople",
+        "Entry of the Theotokos into the Temple",
+        "Apostle Philemon; Martyr Cecilia",
+        "Amphilochius of Iconium; Alexander Nevsky",
+        "Great-martyr Catherine; Great-martyr Mercurius",
+        "Hieromartyrs Clement of Rome and Peter of Alexandria",
+        "Alypius the Stylite",
+        "Great-martyr James the Persian",
+        "Martyr Stephen the New",
+        "Martyr Paramon",
+        "Apostle Andrew the First-called",
+    ],
+    // December
+    &[
+        "Prophet Nahum",
+        "Prophet Habakkuk",
+        "Prophet Zephaniah",
+        "Great-martyr Barbara; John of Damascus",
+        "Sabbas the Sanctified",
+        "Nicholas the Wonderworker",
+        "Ambrose of Milan",
+        "Patapius of Thebes",
+        "Conception of the Theotokos by Anna",
+        "Martyrs Menas, Hermogenes and Eugraphus",
+        "Daniel the Stylite",
+        "Spyridon the Wonderworker",
+        "Martyrs Eustratius and Companions; Martyr Lucy",
+        "Martyrs Thyrsus, Leucius and Callinicus",
+        "Hieromartyr Eleutherius",
+        "Prophet Haggai",
+        "Prophet Daniel and the Three Holy Youths",
+        "Martyr Sebastian and Companions",
+        "Martyr Boniface",
+        "Hieromartyr Ignatius the God-bearer",
+        "Martyr Juliana; Peter of Moscow",
+        "Great-martyr Anastasia",
+        "Ten Martyrs of Crete",
+        "Martyr Eugenia; Eve of the Nativity",
+        "Nativity of Christ",
+        "Synaxis of the Theotokos",
+        "Protomartyr Stephen",
+        "Twenty Thousand Martyrs of Nicomedia",
+        "Holy Innocents",
+        "Martyr Anysia",
+        "Melania the Younger",
+    ],
+];
@@ -0,0 +1,114 @@
+// mod.rs
+// Name days and saints of the day, appended to one calendar's additional info
+
+pub mod czechia;
+pub mod greece;
+pub mod hungary;
+pub mod martyrology;
+pub mod menaion;
+pub mod poland;
+pub mod roman;
+pub mod slovakia;
+pub mod sweden;
+
+use crate::calendar::julian::julian_from_absolute;
+use crate::calendar::liturgical::julian_easter;
+use crate::models::{CalendarDate, UserSettings};
+use crate::utils::append_info;
+use chrono::{Datelike, Duration, NaiveDate};
+
+// One entry per day of a 366-day year; an empty string means nothing is kept that day
+type DayTable = [&'static [&'static str]; 12];
+
+struct Dataset {
+    key: &'static str,
+    name: &'static str,
+    label: &'static str,
+    table: &'static DayTable,
+    // The menaion is kept by Julian (Old Style) date
+    julian: bool,
+}
+
+const DATASETS: [Dataset; 9] = [
+    Dataset { key: "poland", name: "Poland", label: "Name day", table: &poland::NAME_DAYS, julian: false },
+    Dataset { key: "czechia", name: "Czechia", label: "Name day", table: &czechia::NAME_DAYS, julian: false },
+    Dataset { key: "slovakia", name: "Slovakia", label: "Name day", table: &slovakia::NAME_DAYS, julian: false },
+    Dataset { key: "hungary", name: "Hungary", label: "Name day", table: &hungary::NAME_DAYS, julian: false },
+    Dataset { key: "sweden", name: "Sweden", label: "Name day", table: &sweden::NAME_DAYS, julian: false },
+    Dataset { key: "greece", name: "Greece", label: "Name day", table: &greece::NAME_DAYS, julian: false },
+    Dataset { key: "roman", name: "General Roman Calendar", label: "Saint of the day", table: &roman::SAINTS, julian: false },
+    Dataset { key: "martyrology", name: "Roman Martyrology", label: "Saint of the day", table: &martyrology::SAINTS, julian: false },
+    Dataset { key: "orthodox", name: "Orthodox", label: "Saint of the day", table: &menaion::SAINTS, julian: true },
+];
+
+fn lookup(table: &DayTable, month: u32, day: u32) -> Option<&'static str> {
+    table
+        .get(month.wrapping_sub(1) as usize)?
+        .get(day.wrapping_sub(1) as usize)
+        .copied()
+        .filter(|names| !names.is_empty())
+}
+
+// Greek name days, including those that follow Pascha. St George (23 April) and St Mark (25 April)
+// are kept on Bright Monday and Tuesday when their day falls before the Resurrection
+fn greek_names(date: NaiveDate) -> Option<String> {
+    let pascha = julian_easter(date.year());
+    let george = NaiveDate::from_ymd_opt(date.year(), 4, 23).unwrap();
+    let mark = NaiveDate::from_ymd_opt(date.year(), 4, 25).unwrap();
+    let george_moved = george <= pascha;
+    let mark_moved = george_moved && mark <= pascha + Duration::days(1);
+
+    let mut names = Vec::new();
+    match (date - pascha).num_days() {
+        -8 => names.push("Lazaros"),
+        0 => names.push("Anastasios"),
+        1 if george_moved => names.push("Georgios, Georgia"),
+        2 if mark_moved => names.push("Markos"),
+        7 => names.push("Thomas"),
+        _ => {}
+    }
+
+    let moved_away = (date == george && george_moved) || (date == mark && mark_moved);
+    if let Some(fixed) = lookup(&greece::NAME_DAYS, date.month(), date.day()).filter(|_| !moved_away) {
+        names.push(fixed);
+    }
+
+    (!names.is_empty()).then(|| names.join(", "))
+}
+
+fn names_for(dataset: &Dataset, date: NaiveDate) -> Option<String> {
+    if dataset.key == "greece" {
+        return greek_names(date);
+    }
+    let (month, day) = if dataset.julian {
+        let (_, month, day) = julian_from_absolute(date.num_days_from_ce() as i64);
+        (month, day)
+    } else {
+        (date.month(), date.day())
+    };
+    lookup(dataset.table, month, day).map(str::to_string)
+}
+
+// "Name day: …" notes for the datasets chosen in the "name_days" setting; the country is named when there are several
+pub fn name_day_notes(date: NaiveDate, settings: &UserSettings) -> Vec<String> {
+    let keys = settings.name_days.as_deref().unwrap_or(&[]);
+    keys.iter()
+        .filter_map(|key| DATASETS.iter().find(|d| d.key == key.trim().to_lowercase()))
+        .filter_map(|dataset| {
+            let names = names_for(dataset, date)?;
+            Some(if keys.len() > 1 {
+                format!("{} ({}): {}", dataset.label, dataset.name, names)
+            } else {
+                format!("{}: {}", dataset.label, names)
+            })
+        })
+        .collect()
+}
+
+// Adds the notes to the calendar chosen by "name_days_calendar" (the Gregorian one by default)
+pub fn annotate(calendar: &str, date: &mut CalendarDate, day: NaiveDate, settings: &UserSettings) {
+    if settings.name_days_calendar.as_deref().unwrap_or("gregorian") != calendar {
+        return;
+    }
+    append_info(date, name_day_notes(day, settings));
+}
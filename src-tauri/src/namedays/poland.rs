@@ -0,0 +1,407 @@
+// poland.rs
+// Polish name days (imieniny), the names most commonly celebrated on each day
+
+pub const NAME_DAYS: [&[&str]; 12] = [
+    // January
+    &[
+        "Mieczysław, Mieszko",
+        "Bazyli, Grzegorz, Makary",
+        "Danuta, Genowefa, Arletta",
+        "Angelika, Dafroza, Eugeniusz",
+        "Edward, Szymon, Hanna",
+        "Kacper, Melchior, Baltazar",
+        "Lucjan, Julian, Rajmund",
+        "Seweryn, Mścisław, Juliusz",
+        "Marcelina, Marian, Julian",
+        "Wilhelm, Dobrosław, Danuta",
+        "Honorata, Matylda, Teodozjusz",
+        "Arkadiusz, Czesława, Bernard",
+        "Bogumiła, Weronika, Hilary",
+        "Feliks, Nina, Hilary",
+        "Arnold, Izydor, Paweł",
+        "Marceli, Włodzimierz, Walerian",
+        "Antoni, Rościsław, Jan",
+        "Piotr, Małgorzata, Krystyna",
+        "Henryk, Mariusz, Marta",
+        "Fabian, Sebastian",
+        "Agnieszka, Jarosław, Epifaniusz",
+        "Anastazy, Wincenty, Dorota",
+        "Ildefons, Rajmund, Emerencja",
+        "Felicja, Franciszek, Rafał",
+        "Paweł, Miłosz, Tatiana",
+        "Tymoteusz, Michał, Wanda",
+        "Angelika, Przybysław, Jerzy",
+        "Tomasz, Walery, Radomir",
+        "Zdzisław, Franciszek, Józef",
+        "Maciej, Martyna, Teofil",
+        "Jan, Marcela, Ludwika",
+    ],
+    // February
+    &[
+        "Brygida, Ignacy, Seweryn",
+        "Maria, Mirosław, Joanna",
+        "Błażej, Oskar, Hipolit",
+        "Andrzej, Weronika, Joanna",
+        "Agata, Adelajda, Izydor",
+        "Dorota, Bohdan, Paweł",
+        "Ryszard, Romuald, Sulisław",
+        "Hieronim, Sebastian, Ksenia",
+        "Apolonia, Eryka, Cyryl",
+        "Elwira, Jacek, Scholastyka",
+        "Lucjan, Olgierd, Grzegorz",
+        "Eulalia, Radosław, Modest",
+        "Grzegorz, Katarzyna, Jordan",
+        "Walenty, Cyryl, Metody",
+        "Jowita, Faustyn, Zygfryd",
+        "Danuta, Julianna, Daniel",
+        "Aleksy, Zbigniew, Łukasz",
+        "Szymon, Konstancja, Zuzanna",
+        "Arnold, Konrad, Marceli",
+        "Leon, Ludmiła, Leszek",
+        "Eleonora, Fortunat, Piotr",
+        "Małgorzata, Marta, Izabela",
+        "Damian, Roma, Romana",
+        "Maciej, Bogusz, Józef",
+        "Wiktor, Cezary, Wacława",
+        "Mirosław, Aleksander, Jan",
+        "Gabriel, Anastazja, Leander",
+        "Roman, Ludomir, Lech",
+        "Dobronieg, Roman",
+    ],
+    // March
+    &[
+        "Albin, Antonina, Radosław",
+        "Helena, Halszka, Heronim",
+        "Maryna, Kunegunda, Tycjan",
+        "Kazimierz, Łucja, Adrian",
+        "Adrian, Fryderyk, Teofil",
+        "Róża, Wiktor, Jordan",
+        "Tomasz, Felicyta, Perpetua",
+        "Beata, Wincenty, Jan",
+        "Franciszka, Katarzyna, Dominik",
+        "Cyprian, Marcel, Aleksander",
+        "Konstanty, Benedykt, Ernest",
+        "Grzegorz, Józefina, Bernard",
+        "Bożena, Krystyna, Marek",
+        "Leon, Matylda, Michalina",
+        "Klemens, Longin, Ludwika",
+        "Izabela, Oktawia, Hilary",
+        "Patryk, Zbigniew, Gertruda",
+        "Cyryl, Edward, Narcyz",
+        "Józef, Bogdan",
+        "Klaudia, Eufemia, Aleksandra",
+        "Ludomir, Benedykt, Lubomira",
+        "Katarzyna, Bogusław, Jarosław",
+        "Pelagia, Feliks, Katarzyna",
+        "Marek, Gabriel, Katarzyna",
+        "Wieńczysław, Ireneusz, Lucja",
+        "Emanuel, Larysa, Teodor",
+        "Lidia, Ernest, Jan",
+        "Aniela, Sykstus, Jan",
+        "Wiktoryna, Helmut, Eustachy",
+        "Amelia, Aniela, Leonard",
+        "Beniamin, Balbina, Gwido",
+    ],
+    // April
+    &[
+        "Grażyna, Teodora, Hugo",
+        "Władysław, Franciszek, Teodozja",
+        "Ryszard, Pankracy, Jakub",
+        "Izydor, Wacław, Platon",
+        "Irena, Wincenty, Katarzyna",
+        "Izolda, Celestyn, Wilhelm",
+        "Rufin, Herman, Donat",
+        "Cezary, Dionizy, Julia",
+        "Maja, Dymitr, Mariusz",
+        "Michał, Makary, Daniel",
+        "Filip, Leon, Stanisław",
+        "Juliusz, Zenon, Andrzej",
+        "Przemysław, Ida, Hermenegild",
+        "Justyna, Waleriana, Tyburcjusz",
+        "Anastazja, Wiktoryn, Tytus",
+        "Bernadeta, Julia, Ksenia",
+        "Robert, Rudolf, Anicet",
+        "Bogusław, Apoloniusz, Flawiusz",
+        "Adolf, Tymon, Leon",
+        "Agnieszka, Czesław, Szymon",
+        "Anzelm, Bartosz, Feliks",
+        "Kaja, Leonia, Łukasz",
+        "Jerzy, Wojciech, Idzi",
+        "Grzegorz, Aleksander, Feliks",
+        "Marek, Jarosław",
+        "Marzena, Klaudiusz, Maria",
+        "Zyta, Teofil, Piotr",
+        "Waleria, Paweł, Przybysław",
+        "Piotr, Katarzyna, Robert",
+        "Katarzyna, Marian, Jakub",
+    ],
+    // May
+    &[
+        "Józef, Jeremiasz, Filip",
+        "Zygmunt, Atanazy, Anatol",
+        "Maria, Antonina, Aleksander",
+        "Monika, Florian, Michał",
+        "Irena, Waldemar, Pius",
+        "Judyta, Jan, Benedykta",
+        "Gizela, Ludmiła, Benedykt",
+        "Stanisław, Michał, Wiktor",
+        "Grzegorz, Bożydar, Karolina",
+        "Antonina, Izydor, Jan",
+        "Mamert, Franciszek, Igor",
+        "Pankracy, Dominik, Achilles",
+        "Serwacy, Robert, Gloria",
+        "Bonifacy, Dobiesław, Maciej",
+        "Zofia, Nadzieja, Izydor",
+        "Andrzej, Jędrzej, Szymon",
+        "Sławomir, Paschalis, Weronika",
+        "Feliks, Aleksandra, Eryk",
+        "Piotr, Iwo, Mikołaj",
+        "Bernardyn, Bazyli, Aleksander",
+        "Wiktor, Kryspin, Jan",
+        "Helena, Rita, Wiesław",
+        "Iwona, Dezydery, Michał",
+        "Joanna, Zuzanna, Jan",
+        "Grzegorz, Urban, Magdalena",
+        "Filip, Paulina, Ewelina",
+        "Jan, Juliusz, Augustyn",
+        "Jaromir, Justyna, Wilhelm",
+        "Magdalena, Bogumiła, Maksymilian",
+        "Feliks, Ferdynand, Joanna",
+        "Aniela, Petronela, Kamila",
+    ],
+    // June
+    &[
+        "Jakub, Konrad, Justyn",
+        "Marianna, Marcelin, Erazm",
+        "Leszek, Tamara, Karol",
+        "Franciszek, Karol, Kwiryn",
+        "Walter, Bonifacy, Waleria",
+        "Norbert, Laurenty, Klaudiusz",
+        "Robert, Wiesław, Lukrecja",
+        "Medard, Maksym, Seweryn",
+        "Felicjan, Pelagia, Prymus",
+        "Bogumił, Małgorzata, Diana",
+        "Barnaba, Radomił, Feliks",
+        "Janina, Onufry, Leon",
+        "Lucjan, Antoni, Tobiasz",
+        "Bazyli, Elizeusz, Justyna",
+        "Wit, Jolanta, Angelina",
+        "Alina, Benon, Aneta",
+        "Adolf, Laura, Marcjan",
+        "Marina, Elżbieta, Paula",
+        "Gerwazy, Protazy, Julianna",
+        "Bogna, Florentyna, Bogumiła",
+        "Alicja, Alojzy, Rudolf",
+        "Paulina, Tomasz, Jan",
+        "Wanda, Zenon, Józef",
+        "Jan, Danuta",
+        "Łucja, Wilhelm, Dorota",
+        "Jan, Paweł, Zdziwoj",
+        "Władysław, Cyryl, Maryla",
+        "Leon, Ireneusz, Józef",
+        "Piotr, Paweł",
+        "Emilia, Lucyna, Ciechosław",
+    ],
+    // July
+    &[
+        "Halina, Mariusz, Marian",
+        "Urban, Jagoda, Otton",
+        "Jacek, Anatol, Tomasz",
+        "Odo, Malwina, Teodor",
+        "Maria, Antoni, Karolina",
+        "Dominika, Gotard, Łucja",
+        "Benedykt, Cyryl, Metody",
+        "Adrian, Eugeniusz, Elżbieta",
+        "Lukrecja, Weronika, Zenon",
+        "Olaf, Witalis, Amelia",
+        "Olga, Kalina, Benedykt",
+        "Jan, Brunon, Euzebiusz",
+        "Henryk, Jakub, Ernest",
+        "Stella, Bonawentura, Kamil",
+        "Henryk, Włodzimierz, Dawid",
+        "Maria, Eustachy, Benedykt",
+        "Aleksy, Andrzej, Jadwiga",
+        "Erwin, Kamil, Szymon",
+        "Wincenty, Wodzisław, Marcin",
+        "Czesław, Hieronim, Małgorzata",
+        "Daniel, Dalida, Wiktor",
+        "Magdalena, Bolesław, Wawrzyniec",
+        "Bogna, Brygida, Sławosz",
+        "Kinga, Krystyna, Olga",
+        "Jakub, Krzysztof, Walentyna",
+        "Anna, Mirosława, Grażyna",
+        "Lilia, Julia, Natalia",
+        "Innocenty, Wiktor, Aida",
+        "Olaf, Marta, Beatrycze",
+        "Julita, Piotr, Ludmiła",
+        "Ignacy, Lubomir, Helena",
+    ],
+    // August
+    &[
+        "Piotr, Nadia, Justyn",
+        "Karina, Gustaw, Alfons",
+        "Lidia, Nikodem, August",
+        "Dominik, Jan, Mironiega",
+        "Maria, Oswald, Emil",
+        "Sława, Jakub, Stefan",
+        "Kajetan, Dorota, Sykstus",
+        "Cyriak, Emil, Dominik",
+        "Roman, Klara, Jan",
+        "Wawrzyniec, Bogdan, Bernard",
+        "Zuzanna, Lukrecja, Aleksander",
+        "Klara, Innocenty, Lech",
+        "Hipolit, Diana, Radomiła",
+        "Euzebiusz, Maksymilian, Alfred",
+        "Maria, Napoleon, Stefan",
+        "Stefan, Joachim, Roch",
+        "Jacek, Anita, Eliza",
+        "Helena, Bogusław, Ilona",
+        "Jan, Bolesław, Ludwik",
+        "Bernard, Samuel, Sobiesław",
+        "Joanna, Kazimiera, Adolf",
+        "Cezary, Tymoteusz, Zygfryd",
+        "Róża, Filip, Apolinary",
+        "Bartłomiej, Jerzy, Michalina",
+        "Ludwik, Luiza, Józef",
+        "Maria, Aleksander, Zefiryn",
+        "Józef, Monika, Cezary",
+        "Augustyn, Patrycja, Aleksander",
+        "Jan, Beata, Sabina",
+        "Róża, Szczęsny, Adaukt",
+        "Rajmund, Bohdan, Ramona",
+    ],
+    // September
+    &[
+        "Bronisław, Idzi, Bronisława",
+        "Julian, Stefan, Czesław",
+        "Izabela, Szymon, Grzegorz",
+        "Rozalia, Róża, Ida",
+        "Dorota, Wawrzyniec, Teodor",
+        "Beata, Eugeniusz, Zachariasz",
+        "Regina, Melchior, Domasław",
+        "Maria, Adrian, Serafina",
+        "Piotr, Sergiusz, Aureliusz",
+        "Mikołaj, Łukasz, Aldona",
+        "Jacek, Feliks, Prot",
+        "Maria, Gwidon, Radzimir",
+        "Eugenia, Aureliusz, Jan",
+        "Roksana, Bernard, Cyprian",
+        "Albin, Nikodem, Maria",
+        "Edyta, Kornel, Cyprian",
+        "Franciszek, Hildegarda, Robert",
+        "Irma, Józef, Stanisław",
+        "January, Konstancja, Teodor",
+        "Filipina, Eustachy, Fausta",
+        "Hipolit, Jonasz, Mateusz",
+        "Tomasz, Maurycy, Joachim",
+        "Bogusław, Tekla, Liwiusz",
+        "Gerard, Teodor, Tomir",
+        "Aurelia, Władysław, Kleofas",
+        "Wawrzyniec, Kosma, Damian",
+        "Wincenty, Mirabela, Justyna",
+        "Wacław, Tymon, Marek",
+        "Michał, Gabriel, Rafał",
+        "Wera, Honoriusz, Hieronim",
+    ],
+    // October
+    &[
+        "Danuta, Remigiusz, Teresa",
+        "Teofil, Dionizy, Sława",
+        "Teresa, Heliodor, Józefa",
+        "Rozalia, Edwin, Franciszek",
+        "Placyd, Apolinary, Faustyna",
+        "Artur, Brunon, Roman",
+        "Marek, Mirela, Rozaria",
+        "Pelagia, Brygida, Walerian",
+        "Arnold, Dionizy, Wincenty",
+        "Paulina, Daniel, Franciszek",
+        "Emil, Aldona, Maria",
+        "Eustachy, Maksymilian, Witold",
+        "Edward, Mikołaj, Teofil",
+        "Dominik, Fortunata, Kalikst",
+        "Teresa, Jadwiga, Tekla",
+        "Gaweł, Ambroży, Florentyna",
+        "Wiktor, Marian, Ignacy",
+        "Łukasz, Julian, Piotr",
+        "Piotr, Ziemowit, Fryda",
+        "Irena, Kleopatra, Jan",
+        "Urszula, Hilary, Celina",
+        "Halka, Filip, Salomea",
+        "Teodor, Seweryn, Ignacy",
+        "Rafał, Marcin, Antoni",
+        "Kryspin, Daria, Bonifacy",
+        "Lucjan, Ewaryst, Dymitr",
+        "Iwona, Sabina, Wincenty",
+        "Szymon, Tadeusz, Juda",
+        "Euzebia, Wioletta, Narcyz",
+        "Zenobia, Przemysław, Edmund",
+        "Urban, Saturnin, Krzysztof",
+    ],
+    // November
+    &[
+        "Seweryn, Wiktoryna, Konradyna",
+        "Bohdana, Tobiasz, Ambroży",
+        "Sylwia, Marcin, Hubert",
+        "Karol, Olgierd, Emeryk",
+        "Elżbieta, Sławomir, Dominik",
+        "Feliks, Leonard, Ziemowit",
+        "Antoni, Żelisław, Florentyna",
+        "Sewer, Hadrian, Seweryn",
+        "Aleksander, Ludwik, Teodor",
+        "Leon, Ludomir, Andrzej",
+        "Marcin, Prot, Bartłomiej",
+        "Renata, Witold, Jozafat",
+        "Mikołaj, Stanisław, Arkadiusz",
+        "Emil, Serafin, Ernest",
+        "Albert, Leopold, Artur",
+        "Gertruda, Edmund, Marek",
+        "Grzegorz, Salomea, Dionizy",
+        "Roman, Klaudyna, Otto",
+        "Elżbieta, Seweryna, Paweł",
+        "Edmund, Feliks, Anatol",
+        "Janusz, Konrad, Maria",
+        "Cecylia, Marek, Wszebor",
+        "Adela, Felicyta, Klemens",
+        "Flora, Emma, Jan",
+        "Katarzyna, Erazm, Klemens",
+        "Delfina, Sylwester, Konrad",
+        "Walery, Wirgiliusz, Maksymilian",
+        "Lesław, Zdzisław, Stefan",
+        "Błażej, Saturnin, Fryderyk",
+        "Andrzej, Maura, Justyna",
+    ],
+    // December
+    &[
+        "Natalia, Eligiusz, Edmund",
+        "Balbina, Bibiana, Paulina",
+        "Franciszek, Ksawery, Kasjan",
+        "Barbara, Krystian, Piotr",
+        "Saba, Kryspina, Pęcisław",
+        "Mikołaj, Jarema, Dionizja",
+        "Marcin, Ambroży, Agaton",
+        "Maria, Wirginia, Światozar",
+        "Wiesław, Leokadia, Joanna",
+        "Julia, Daniel, Maria",
+        "Damazy, Waldemar, Daniel",
+        "Dagmara, Aleksander, Ada",
+        "Łucja, Otylia, Jan",
+        "Alfred, Izydor, Jan",
+        "Nina, Celina, Walerian",
+        "Albina, Zdzisława, Alicja",
+        "Olimpia, Łazarz, Jolanta",
+        "Gracjan, Bogusław, Laurencja",
+        "Gabriela, Dariusz, Urban",
+        "Bogumiła, Dominik, Zefiryn",
+        "Tomasz, Tomisław, Piotr",
+        "Zenon, Honorata, Franciszka",
+        "Wiktoria, Sławomir, Jan",
+        "Adam, Ewa, Irmina",
+        "Anastazja, Eugenia",
+        "Dionizy, Szczepan, Wrociwoj",
+        "Jan, Żaneta, Maksym",
+        "Teofila, Godzisław, Dawid",
+        "Dawid, Tomasz, Dominik",
+        "Eugeniusz, Sabina, Dawid",
+        "Sylwester, Melania, Mariusz",
+    ],
+];
@@ -0,0 +1,408 @@
+// roman.rs
+// Saints of the General Roman Calendar, one celebration a day; some of the days it leaves free name a saint
+// widely kept on them
+
+pub const SAINTS: [&[&str]; 12] = [
+    // January
+    &[
+        "Mary, Mother of God",
+        "Basil the Great and Gregory Nazianzen",
+        "Most Holy Name of Jesus",
+        "Angela of Foligno",
+        "Simeon Stylites",
+        "Epiphany of the Lord",
+        "Raymond of Penyafort",
+        "Severinus of Noricum",
+        "Adrian of Canterbury",
+        "Gregory of Nyssa",
+        "Theodosius the Cenobiarch",
+        "Marguerite Bourgeoys",
+        "Hilary of Poitiers",
+        "Felix of Nola",
+        "Paul the Hermit",
+        "Marcellus I",
+        "Anthony, Abbot",
+        "Margaret of Hungary",
+        "Wulfstan of Worcester",
+        "Fabian; Sebastian",
+        "Agnes",
+        "Vincent, Deacon",
+        "Ildephonsus of Toledo",
+        "Francis de Sales",
+        "Conversion of St Paul",
+        "Timothy and Titus",
+        "Angela Merici",
+        "Thomas Aquinas",
+        "Gildas the Wise",
+        "Hyacintha Mariscotti",
+        "John Bosco",
+    ],
+    // February
+    &[
+        "Brigid of Kildare",
+        "Presentation of the Lord",
+        "Blaise; Ansgar",
+        "Joan of France",
+        "Agatha",
+        "Paul Miki and Companions",
+        "",
+        "Jerome Emiliani; Josephine Bakhita",
+        "Apollonia",
+        "Scholastica",
+        "Our Lady of Lourdes",
+        "",
+        "Catherine de' Ricci",
+        "Cyril and Methodius",
+        "Claude de la Colombière",
+        "Juliana of Nicomedia",
+        "Seven Holy Founders of the Servite Order",
+        "Simeon of Jerusalem",
+        "Conrad of Piacenza",
+        "Francisco and Jacinta Marto",
+        "Peter Damian",
+        "Chair of St Peter",
+        "Polycarp",
+        "",
+        "Walburga",
+        "Porphyrius of Gaza",
+        "Gregory of Narek",
+        "Romanus of Condat",
+        "Oswald of Worcester",
+    ],
+    // March
+    &[
+        "David of Wales",
+        "Chad of Mercia",
+        "Katharine Drexel",
+        "Casimir",
+        "John Joseph of the Cross",
+        "Colette",
+        "Perpetua and Felicity",
+        "John of God",
+        "Frances of Rome",
+        "John Ogilvie",
+        "Eulogius of Córdoba",
+        "Maximilian of Tebessa",
+        "Leander of Seville",
+        "Matilda",
+        "Louise de Marillac",
+        "Clement Mary Hofbauer",
+        "Patrick",
+        "Cyril of Jerusalem",
+        "Joseph, Spouse of the Blessed Virgin Mary",
+        "Cuthbert",
+        "Nicholas of Flüe",
+        "Lea of Rome",
+        "Turibius of Mogrovejo",
+        "Oscar Romero",
+        "Annunciation of the Lord",
+        "Ludger",
+        "Rupert of Salzburg",
+        "",
+        "",
+        "John Climacus",
+        "Benjamin, Deacon",
+    ],
+    // April
+    &[
+        "Hugh of Grenoble",
+        "Francis of Paola",
+        "Richard of Chichester",
+        "Isidore",
+        "Vincent Ferrer",
+        "",
+        "John Baptist de la Salle",
+        "Julie Billiart",
+        "",
+        "",
+        "Stanislaus",
+        "Julius I",
+        "Martin I",
+        "Lidwina",
+        "",
+        "Bernadette Soubirous",
+        "",
+        "",
+        "Leo IX",
+        "Agnes of Montepulciano",
+        "Anselm",
+        "",
+        "George; Adalbert",
+        "Fidelis of Sigmaringen",
+        "Mark, Evangelist",
+        "",
+        "Zita",
+        "Peter Chanel; Louis Grignion de Montfort",
+        "Catherine of Siena",
+        "Pius V",
+    ],
+    // May
+    &[
+        "Joseph the Worker",
+        "Athanasius",
+        "Philip and James, Apostles",
+        "Florian",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "John of Ávila",
+        "",
+        "Nereus and Achilleus; Pancras",
+        "Our Lady of Fátima",
+        "Matthias, Apostle",
+        "Isidore the Farmer",
+        "Brendan the Navigator",
+        "Paschal Baylon",
+        "John I",
+        "Celestine V",
+        "Bernardine of Siena",
+        "Christopher Magallanes and Companions",
+        "Rita of Cascia",
+        "",
+        "",
+        "Bede the Venerable; Gregory VII; Mary Magdalene de' Pazzi",
+        "Philip Neri",
+        "Augustine of Canterbury",
+        "",
+        "Paul VI",
+        "Joan of Arc",
+        "Visitation of the Blessed Virgin Mary",
+    ],
+    // June
+    &[
+        "Justin Martyr",
+        "Marcellinus and Peter",
+        "Charles Lwanga and Companions",
+        "",
+        "Boniface",
+        "Norbert",
+        "",
+        "",
+        "Ephrem",
+        "",
+        "Barnabas, Apostle",
+        "",
+        "Anthony of Padua",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "Romuald",
+        "",
+        "Aloysius Gonzaga",
+        "Paulinus of Nola; John Fisher and Thomas More",
+        "",
+        "Nativity of St John the Baptist",
+        "",
+        "",
+        "Cyril of Alexandria",
+        "Irenaeus",
+        "Peter and Paul, Apostles",
+        "First Martyrs of the Holy Roman Church",
+    ],
+    // July
+    &[
+        "",
+        "",
+        "Thomas, Apostle",
+        "Elizabeth of Portugal",
+        "Anthony Zaccaria",
+        "Maria Goretti",
+        "",
+        "",
+        "Augustine Zhao Rong and Companions",
+        "",
+        "Benedict",
+        "",
+        "Henry",
+        "Camillus de Lellis",
+        "Bonaventure",
+        "Our Lady of Mount Carmel",
+        "",
+        "",
+        "",
+        "Apollinaris",
+        "Lawrence of Brindisi",
+        "Mary Magdalene",
+        "Bridget of Sweden",
+        "Sharbel Makhluf",
+        "James, Apostle",
+        "Joachim and Anne",
+        "",
+        "",
+        "Martha, Mary and Lazarus",
+        "Peter Chrysologus",
+        "Ignatius of Loyola",
+    ],
+    // August
+    &[
+        "Alphonsus Liguori",
+        "Eusebius of Vercelli; Peter Julian Eymard",
+        "",
+        "John Vianney",
+        "Dedication of the Basilica of St Mary Major",
+        "Transfiguration of the Lord",
+        "Sixtus II and Companions; Cajetan",
+        "Dominic",
+        "Teresa Benedicta of the Cross",
+        "Lawrence",
+        "Clare",
+        "Jane Frances de Chantal",
+        "Pontian and Hippolytus",
+        "Maximilian Kolbe",
+        "Assumption of the Blessed Virgin Mary",
+        "Stephen of Hungary",
+        "",
+        "",
+        "John Eudes",
+        "Bernard",
+        "Pius X",
+        "Queenship of the Blessed Virgin Mary",
+        "Rose of Lima",
+        "Bartholomew, Apostle",
+        "Louis; Joseph Calasanz",
+        "",
+        "Monica",
+        "Augustine",
+        "Passion of St John the Baptist",
+        "",
+        "",
+    ],
+    // September
+    &[
+        "",
+        "",
+        "Gregory the Great",
+        "",
+        "Teresa of Calcutta",
+        "",
+        "",
+        "Nativity of the Blessed Virgin Mary",
+        "Peter Claver",
+        "",
+        "",
+        "Most Holy Name of Mary",
+        "John Chrysostom",
+        "Exaltation of the Holy Cross",
+        "Our Lady of Sorrows",
+        "Cornelius and Cyprian",
+        "Robert Bellarmine; Hildegard of Bingen",
+        "",
+        "Januarius",
+        "Andrew Kim Tae-gon, Paul Chong Ha-sang and Companions",
+        "Matthew, Apostle and Evangelist",
+        "",
+        "Pius of Pietrelcina",
+        "",
+        "",
+        "Cosmas and Damian",
+        "Vincent de Paul",
+        "Wenceslaus; Lawrence Ruiz and Companions",
+        "Michael, Gabriel and Raphael, Archangels",
+        "Jerome",
+    ],
+    // October
+    &[
+        "Thérèse of the Child Jesus",
+        "Guardian Angels",
+        "",
+        "Francis of Assisi",
+        "Faustina Kowalska",
+        "Bruno",
+        "Our Lady of the Rosary",
+        "",
+        "Denis and Companions; John Leonardi",
+        "",
+        "John XXIII",
+        "",
+        "",
+        "Callistus I",
+        "Teresa of Jesus",
+        "Hedwig; Margaret Mary Alacoque",
+        "Ignatius of Antioch",
+        "Luke, Evangelist",
+        "John de Brébeuf, Isaac Jogues and Companions; Paul of the Cross",
+        "",
+        "",
+        "John Paul II",
+        "John of Capistrano",
+        "Anthony Mary Claret",
+        "",
+        "",
+        "",
+        "Simon and Jude, Apostles",
+        "",
+        "",
+        "",
+    ],
+    // November
+    &[
+        "All Saints",
+        "All Souls",
+        "Martin de Porres",
+        "Charles Borromeo",
+        "",
+        "",
+        "",
+        "",
+        "Dedication of the Lateran Basilica",
+        "Leo the Great",
+        "Martin of Tours",
+        "Josaphat",
+        "",
+        "",
+        "Albert the Great",
+        "Margaret of Scotland; Gertrude",
+        "Elizabeth of Hungary",
+        "Dedication of the Basilicas of Sts Peter and Paul",
+        "",
+        "",
+        "Presentation of the Blessed Virgin Mary",
+        "Cecilia",
+        "Clement I; Columban",
+        "Andrew Dũng-Lạc and Companions",
+        "Catherine of Alexandria",
+        "",
+        "",
+        "",
+        "",
+        "Andrew, Apostle",
+    ],
+    // December
+    &[
+        "",
+        "",
+        "Francis Xavier",
+        "John Damascene",
+        "",
+        "Nicholas",
+        "Ambrose",
+        "Immaculate Conception of the Blessed Virgin Mary",
+        "Juan Diego",
+        "Our Lady of Loreto",
+        "Damasus I",
+        "Our Lady of Guadalupe",
+        "Lucy",
+        "John of the Cross",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "Peter Canisius",
+        "",
+        "John of Kanty",
+        "",
+        "Nativity of the Lord",
+        "Stephen, First Martyr",
+        "John, Apostle and Evangelist",
+        "Holy Innocents",
+        "Thomas Becket",
+        "",
+        "Sylvester I",
+    ],
+];
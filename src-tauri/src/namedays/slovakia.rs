@@ -0,0 +1,407 @@
+// slovakia.rs
+// Slovak name days (meniny); state holidays have no name
+
+pub const NAME_DAYS: [&[&str]; 12] = [
+    // January
+    &[
+        "",
+        "Alexandra, Karina",
+        "Daniela",
+        "Drahoslav",
+        "Andrea",
+        "Antónia",
+        "Bohuslava",
+        "Severín",
+        "Alexej",
+        "Dáša",
+        "Malvína",
+        "Ernest",
+        "Rastislav",
+        "Radovan",
+        "Dobroslav",
+        "Kristína",
+        "Nataša",
+        "Bohdana",
+        "Drahomíra, Mário",
+        "Dalibor",
+        "Vincent",
+        "Zora",
+        "Miloš",
+        "Timotej",
+        "Gejza",
+        "Tamara",
+        "Bohuš",
+        "Alfonz",
+        "Gašpar",
+        "Ema",
+        "Emil",
+    ],
+    // February
+    &[
+        "Tatiana",
+        "Erika, Erik",
+        "Blažej",
+        "Veronika",
+        "Agáta",
+        "Dorota",
+        "Vanda",
+        "Zoja",
+        "Zdenko",
+        "Gabriela",
+        "Dezider",
+        "Perla",
+        "Arpád",
+        "Valentín",
+        "Pravoslav",
+        "Ida, Liana",
+        "Miloslava",
+        "Jaromír",
+        "Vlasta",
+        "Lívia",
+        "Eleonóra",
+        "Etela",
+        "Roman, Romana",
+        "Matej",
+        "Frederik, Frederika",
+        "Viktor",
+        "Alexander",
+        "Zlatica",
+        "Radomír",
+    ],
+    // March
+    &[
+        "Albín",
+        "Anežka",
+        "Bohumil, Bohumila",
+        "Kazimír",
+        "Fridrich",
+        "Radoslav, Radoslava",
+        "Tomáš",
+        "Alan, Alana",
+        "Františka",
+        "Branislav, Bruno",
+        "Angela, Angelika",
+        "Gregor",
+        "Vlastimil",
+        "Matilda",
+        "Svetlana",
+        "Boleslav",
+        "Ľubica",
+        "Eduard",
+        "Jozef",
+        "Víťazoslav, Klaudius",
+        "Blahoslav",
+        "Beňadik",
+        "Adrián",
+        "Gabriel",
+        "Marián",
+        "Emanuel",
+        "Alena",
+        "Soňa",
+        "Miroslav",
+        "Vieroslava",
+        "Benjamín",
+    ],
+    // April
+    &[
+        "Hugo",
+        "Zita",
+        "Richard",
+        "Izidor",
+        "Miroslava",
+        "Irena",
+        "Zoltán",
+        "Albert",
+        "Milena",
+        "Igor",
+        "Július",
+        "Estera",
+        "Aleš",
+        "Justína",
+        "Fedor",
+        "Dana, Danica",
+        "Rudolf, Rudolfína",
+        "Valér",
+        "Jela",
+        "Marcel",
+        "Ervín",
+        "Slavomír",
+        "Vojtech",
+        "Juraj",
+        "Marek",
+        "Jaroslava",
+        "Jaroslav",
+        "Jarmila",
+        "Lea",
+        "Anastázia",
+    ],
+    // May
+    &[
+        "",
+        "Žigmund",
+        "Galina, Timea",
+        "Florián",
+        "Lesana, Lesia",
+        "Hermína",
+        "Monika",
+        "Ingrida",
+        "Roland",
+        "Viktória",
+        "Blažena",
+        "Pankrác",
+        "Servác",
+        "Bonifác",
+        "Žofia, Sofia",
+        "Svetozár",
+        "Gizela, Aneta",
+        "Viola",
+        "Gertrúda",
+        "Bernard",
+        "Zina",
+        "Júlia, Juliana",
+        "Želmíra",
+        "Ela",
+        "Urban, Vivien",
+        "Dušan",
+        "Iveta",
+        "Viliam",
+        "Vilma",
+        "Ferdinand",
+        "Petrana, Petronela",
+    ],
+    // June
+    &[
+        "Žaneta",
+        "Xénia, Oxana",
+        "Karolína",
+        "Lenka",
+        "Laura",
+        "Norbert",
+        "Róbert, Robin",
+        "Medard",
+        "Stanislava",
+        "Margaréta",
+        "Dobroslava",
+        "Zlatko",
+        "Anton",
+        "Vasil",
+        "Vít",
+        "Blanka, Bianka",
+        "Adolf",
+        "Vratislav",
+        "Alfréd",
+        "Valéria",
+        "Alojz",
+        "Paulína",
+        "Sidónia",
+        "Ján",
+        "Olívia, Tadeáš",
+        "Adriána",
+        "Ladislav, Ladislava",
+        "Beáta",
+        "Peter, Pavol, Petra",
+        "Melánia",
+    ],
+    // July
+    &[
+        "Diana",
+        "Berta",
+        "Miloslav",
+        "Prokop",
+        "Cyril, Metod",
+        "Patrik, Patrícia",
+        "Oliver",
+        "Ivan",
+        "Lujza",
+        "Amália",
+        "Milota",
+        "Nina",
+        "Margita",
+        "Kamil",
+        "Henrich",
+        "Drahomír, Rút",
+        "Bohuslav",
+        "Kamila",
+        "Dušana",
+        "Iľja, Eliáš",
+        "Daniel",
+        "Magdaléna",
+        "Oľga",
+        "Vladimír",
+        "Jakub, Timur",
+        "Anna, Hana, Anita",
+        "Božena",
+        "Krištof",
+        "Marta",
+        "Libuša",
+        "Ignác",
+    ],
+    // August
+    &[
+        "Božidara",
+        "Gustáv",
+        "Jerguš",
+        "Dominik, Dominika",
+        "Hortenzia",
+        "Jozefína",
+        "Štefánia",
+        "Oskar",
+        "Ľubomíra",
+        "Vavrinec",
+        "Zuzana",
+        "Darina",
+        "Ľubomír",
+        "Mojmír",
+        "Marcela",
+        "Leonard",
+        "Milica",
+        "Elena, Helena",
+        "Lýdia",
+        "Anabela, Liliana",
+        "Jana",
+        "Tichomír",
+        "Filip",
+        "Bartolomej",
+        "Ľudovít",
+        "Samuel",
+        "Silvia",
+        "Augustín",
+        "Nikola, Nikolaj",
+        "Ružena",
+        "Nora",
+    ],
+    // September
+    &[
+        "Drahoslava",
+        "Linda, Rebeka",
+        "Belo",
+        "Rozália",
+        "Regína",
+        "Alica",
+        "Marianna",
+        "Miriama",
+        "Martina",
+        "Oleg",
+        "Bystrík",
+        "Mária, Marlena",
+        "Ctibor",
+        "Ľudomil",
+        "Jolana",
+        "Ľudmila",
+        "Olympia",
+        "Eugénia",
+        "Konštantín",
+        "Ľuboslav, Ľuboslava",
+        "Matúš",
+        "Móric",
+        "Zdenka",
+        "Ľuboš, Ľubor",
+        "Vladislav, Vladislava",
+        "Edita",
+        "Cyprián",
+        "Václav",
+        "Michal, Michaela",
+        "Jarolím",
+    ],
+    // October
+    &[
+        "Arnold",
+        "Levoslav",
+        "Stela",
+        "František",
+        "Viera",
+        "Natália",
+        "Eliška",
+        "Brigita",
+        "Dionýz",
+        "Slavomíra",
+        "Valentína",
+        "Maximilián",
+        "Koloman",
+        "Boris",
+        "Terézia",
+        "Vladimíra",
+        "Hedviga",
+        "Lukáš",
+        "Kristián",
+        "Vendelín",
+        "Uršuľa",
+        "Sergej",
+        "Alojzia",
+        "Kvetoslava",
+        "Aurel",
+        "Demeter",
+        "Sabína",
+        "Dobromila",
+        "Klára",
+        "Šimon, Simona",
+        "Aurélia",
+    ],
+    // November
+    &[
+        "Denis, Denisa",
+        "",
+        "Hubert",
+        "Karol",
+        "Imrich",
+        "Renáta",
+        "René",
+        "Bohumír",
+        "Teodor",
+        "Tibor",
+        "Martin, Maroš",
+        "Svätopluk",
+        "Stanislav",
+        "Irma",
+        "Leopold",
+        "Agnesa",
+        "Klaudia",
+        "Eugen",
+        "Alžbeta",
+        "Félix",
+        "Elvíra",
+        "Cecília",
+        "Klement",
+        "Emília",
+        "Katarína",
+        "Kornel",
+        "Milan",
+        "Henrieta",
+        "Vratko",
+        "Ondrej, Andrej",
+    ],
+    // December
+    &[
+        "Edmund",
+        "Bibiána",
+        "Oldrich",
+        "Barbora, Barbara",
+        "Oto",
+        "Mikuláš",
+        "Ambróz",
+        "Marína",
+        "Izabela",
+        "Radúz",
+        "Hilda",
+        "Otília",
+        "Lucia",
+        "Branislava, Bronislava",
+        "Ivica",
+        "Albína",
+        "Kornélia",
+        "Sláva, Slávka",
+        "Judita",
+        "Dagmara",
+        "Bohdan",
+        "Adela",
+        "Nadežda",
+        "Adam, Eva",
+        "",
+        "Štefan",
+        "Filoména",
+        "Ivana, Ivona",
+        "Milada",
+        "Dávid",
+        "Silvester",
+    ],
+];
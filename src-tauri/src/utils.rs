@@ -1,3 +1,5 @@
+use crate::models::CalendarDate;
+
 pub fn resolve_date_format(user_value: Option<&str>, show_seconds: bool) -> String {
     let mut format = match user_value {
         Some("military") => "%A, %B %d, %Y %H:%M".to_string(),
@@ -13,4 +15,44 @@ pub fn resolve_date_format(user_value: Option<&str>, show_seconds: bool) -> Stri
     }
 
     format
-}
\ No newline at end of file
+}
+
+pub fn roman_numeral(n: u32) -> String {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"),
+        (100, "C"), (90, "XC"), (50, "L"), (40, "XL"),
+        (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+    ];
+
+    let mut rem = n;
+    let mut out = String::new();
+    for (value, symbol) in NUMERALS.iter() {
+        while rem >= *value {
+            out.push_str(symbol);
+            rem -= value;
+        }
+    }
+    out
+}
+
+// English ordinal: 1st, 2nd, 3rd, 4th, 11th, 21st
+pub fn ordinal(n: i64) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+// Appends notes to a calendar's additional info, separated by " · "
+pub fn append_info(date: &mut CalendarDate, notes: Vec<String>) {
+    if notes.is_empty() {
+        return;
+    }
+    let mut parts: Vec<String> = date.additional_info.take().into_iter().filter(|s| !s.is_empty()).collect();
+    parts.extend(notes);
+    date.additional_info = Some(parts.join(" · "));
+}
@@ -5,6 +5,15 @@ import CalendarScreen from './components/CalendarScreen';
 import LoginScreen from './components/LoginScreen';
 import './App.css';
 
+interface Person {
+  name: string;
+  birth_date?: string | null;
+  born_after_sunset?: boolean | null;
+  death_date?: string | null;
+  died_after_sunset?: boolean | null;
+  gender?: string | null;
+}
+
 interface UserSettings {
   password: string;
   background_type: string;
@@ -16,10 +25,43 @@ interface UserSettings {
   auto_lock_enabled?: boolean | null;
   auto_lock_minutes?: number | null;
   show_seconds?: boolean | null;
+  show_decimal_time?: boolean | null;
   date_format?: string | null;
   theme?: string | null;
   sound_enabled?: boolean | null;
   sound_file?: string | null;
+  historical_country?: string | null;
+  historical_dual_dating?: boolean | null;
+  historical_new_year?: string | null;
+  roman_show_nundinal_letter?: boolean | null;
+  byzantine_greek_months?: boolean | null;
+  french_revolutionary_leap_rule?: string | null;
+  jewish_israel_schedule?: boolean | null;
+  jewish_learning_cycles?: string[] | null;
+  jewish_yahrzeit_adar?: string | null;
+  jewish_hebrew_date?: boolean | null;
+  jewish_hide_thousands?: boolean | null;
+  jewish_gematria_punctuation?: string | null;
+  liturgical_tradition?: string | null;
+  name_days?: string[] | null;
+  name_days_calendar?: string | null;
+  people?: Person[] | null;
+  latitude?: number | null;
+  longitude?: number | null;
+  elevation?: number | null;
+  zmanim_candle_lighting_minutes?: number | null;
+  zmanim_tzeit_minutes?: number | null;
+  zmanim_mga_minutes?: number | null;
+  prayer_method?: string | null;
+  prayer_asr?: string | null;
+  prayer_high_latitude?: string | null;
+  prayer_imsak?: boolean | null;
+  show_next_prayer?: boolean | null;
+  moon_names?: string | null;
+  show_sun_times?: boolean | null;
+  zodiac_ayanamsa?: string | null;
+  zodiac_calendar?: string | null;
+  clocks?: string[] | null;
 }
 
 type AppScreen = 'calendar' | 'login' | 'hidden';
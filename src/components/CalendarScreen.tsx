@@ -12,10 +12,38 @@ interface CalendarDate {
 // Or create a minimal interface for just the relevant settings
 interface UserSettings {
   show_seconds: boolean | null;
+  show_decimal_time: boolean | null;
   date_format: string | null;
+  show_next_prayer?: boolean | null;
+  clocks?: string[] | null;
+  latitude?: number | null;
+  longitude?: number | null;
+  elevation?: number | null;
   // Add other settings if needed for frontend logic
 }
 
+interface PrayerTimes {
+  method: string;
+  next_prayer?: string | null;
+  next_prayer_time?: string | null;
+}
+
+interface ClockReading {
+  label: string;
+  time: string;
+}
+
+// French Republican decimal time: ten hours a day, a hundred minutes an hour, a hundred seconds a minute
+const formatDecimalTime = (date: Date, showSeconds: boolean): string => {
+  const msSinceMidnight =
+    ((date.getHours() * 60 + date.getMinutes()) * 60 + date.getSeconds()) * 1000 + date.getMilliseconds();
+  const decimalSeconds = Math.floor(msSinceMidnight / 864);
+  const hours = Math.floor(decimalSeconds / 10000);
+  const minutes = String(Math.floor(decimalSeconds / 100) % 100).padStart(2, '0');
+  const seconds = String(decimalSeconds % 100).padStart(2, '0');
+  return showSeconds ? `${hours}:${minutes}:${seconds}` : `${hours}:${minutes}`;
+};
+
 interface CalendarScreenProps {
   onProceed: () => void;
 }
@@ -25,6 +53,8 @@ const CalendarScreen: React.FC<CalendarScreenProps> = ({ onProceed }) => {
   const [calendarDates, setCalendarDates] = useState<CalendarDate[]>([]);
   const [userSettings, setUserSettings] = useState<UserSettings | null>(null); // State to hold user settings
   const [isLoading, setIsLoading] = useState(true);
+  const [prayerTimes, setPrayerTimes] = useState<PrayerTimes | null>(null);
+  const [clocks, setClocks] = useState<ClockReading[]>([]);
 
   // Function to load settings
   const loadUserSettings = useCallback(async () => {
@@ -36,7 +66,7 @@ const CalendarScreen: React.FC<CalendarScreenProps> = ({ onProceed }) => {
     } catch (error) {
       console.error('Failed to load user settings:', error);
       // Fallback or handle error
-      setUserSettings({ show_seconds: true, date_format: 'military' }); // Default fallback
+      setUserSettings({ show_seconds: true, show_decimal_time: null, date_format: 'military' }); // Default fallback
     }
   }, []);
 
@@ -57,11 +87,6 @@ const CalendarScreen: React.FC<CalendarScreenProps> = ({ onProceed }) => {
     // Load user settings on component mount
     loadUserSettings();
 
-    // Update client-side time every second
-    const timeInterval = setInterval(() => {
-      setCurrentTime(new Date());
-    }, 1000);
-
     // Load ALL calendar dates initially (for non-Gregorian displays)
     loadOtherCalendarDates();
 
@@ -101,7 +126,6 @@ const CalendarScreen: React.FC<CalendarScreenProps> = ({ onProceed }) => {
     document.addEventListener('click', handleInteraction);
 
     return () => {
-      clearInterval(timeInterval);
       clearInterval(calendarInterval);
       clearTimeout(midnightTimeout);
       document.removeEventListener('keydown', handleInteraction);
@@ -109,6 +133,94 @@ const CalendarScreen: React.FC<CalendarScreenProps> = ({ onProceed }) => {
     };
   }, [onProceed, loadUserSettings, loadOtherCalendarDates]); // Dependencies updated
 
+  // Update client-side time every second, or on each decimal second (0.864 s) with decimal time
+  useEffect(() => {
+    if (!userSettings?.show_decimal_time) {
+      const timeInterval = setInterval(() => {
+        setCurrentTime(new Date());
+      }, 1000);
+
+      return () => {
+        clearInterval(timeInterval);
+      };
+    }
+
+    let decimalTimeout: ReturnType<typeof setTimeout>;
+    const tick = () => {
+      const now = new Date();
+      setCurrentTime(now);
+      // Schedule against the next boundary so the ticks do not drift
+      decimalTimeout = setTimeout(tick, 864 - (now.getTime() - new Date(now).setHours(0, 0, 0, 0)) % 864);
+    };
+
+    tick();
+
+    return () => {
+      clearTimeout(decimalTimeout);
+    };
+  }, [userSettings]);
+
+  // Alternative clocks from the "clocks" setting, read once a second from the settings already loaded
+  useEffect(() => {
+    if (!userSettings?.clocks?.length) {
+      setClocks([]);
+      return;
+    }
+
+    // Only what the clocks read goes over IPC, not the whole settings file
+    const { clocks: clockKeys, show_seconds, latitude, longitude, elevation } = userSettings;
+    const clockSettings = { clocks: clockKeys, show_seconds, latitude, longitude, elevation };
+
+    const updateClocks = async () => {
+      try {
+        setClocks(await invoke<ClockReading[]>('get_clocks', { settings: clockSettings }));
+      } catch (error) {
+        console.error('Failed to load clocks:', error);
+      }
+    };
+
+    updateClocks();
+    const clockInterval = setInterval(updateClocks, 1000);
+
+    return () => {
+      clearInterval(clockInterval);
+    };
+  }, [userSettings]);
+
+  // Today's prayer times; reloaded every minute so the next prayer moves on once its time has come
+  useEffect(() => {
+    if (!userSettings?.show_next_prayer) {
+      return;
+    }
+
+    const updatePrayerTimes = async () => {
+      try {
+        setPrayerTimes(await invoke<PrayerTimes>('get_prayer_times'));
+      } catch (error) {
+        console.error('Failed to load prayer times:', error);
+      }
+    };
+
+    updatePrayerTimes();
+    const prayerInterval = setInterval(updatePrayerTimes, 60000);
+
+    return () => {
+      clearInterval(prayerInterval);
+    };
+  }, [userSettings]);
+
+  // "Asr in 23 min", counted down against the ticking clock
+  let nextPrayerDisplay = '';
+  if (userSettings?.show_next_prayer && prayerTimes?.next_prayer && prayerTimes.next_prayer_time) {
+    const minutes = Math.max(
+      0,
+      Math.ceil((new Date(prayerTimes.next_prayer_time).getTime() - currentTime.getTime()) / 60000),
+    );
+    const hours = Math.floor(minutes / 60);
+    const countdown = hours > 0 ? `${hours} h ${minutes % 60} min` : `${minutes} min`;
+    nextPrayerDisplay = `${prayerTimes.next_prayer} in ${countdown}`;
+  }
+
   // --- Formatting for the main Gregorian display ---
   let mainTimeDisplay = '';
   let mainDateDisplay = '';
@@ -143,7 +255,9 @@ const CalendarScreen: React.FC<CalendarScreenProps> = ({ onProceed }) => {
       timeOptions.hour12 = false;
     }
 
-    mainTimeDisplay = currentTime.toLocaleTimeString('en-US', timeOptions);
+    mainTimeDisplay = userSettings.show_decimal_time
+      ? formatDecimalTime(currentTime, showSeconds)
+      : currentTime.toLocaleTimeString('en-US', timeOptions);
 
     // Determine date format options
     let dateOptions: Intl.DateTimeFormatOptions = {
@@ -175,6 +289,9 @@ const CalendarScreen: React.FC<CalendarScreenProps> = ({ onProceed }) => {
     });
   }
 
+  // Notes the backend adds to the Gregorian date (sun times, name days, zodiac), shown under the main date
+  const gregorianInfo = calendarDates.find((cal) => cal.system.toLowerCase().includes('gregorian'))?.additional_info;
+
   // Find French Revolutionary calendar info
   const frenchRevolutionaryInfo = calendarDates.find(
     (cal) =>
@@ -189,15 +306,42 @@ const CalendarScreen: React.FC<CalendarScreenProps> = ({ onProceed }) => {
         {/* Large time display */}
         <div className="time-display-large">{mainTimeDisplay}</div>
 
+        {/* Alternative clocks, side by side */}
+        {clocks.length > 0 && (
+          <div className="alternative-clocks">
+            {clocks.map((clock, index) => (
+              <div key={index} className="alternative-clock">
+                <span className="alternative-clock-time">{clock.time}</span>
+                <span className="alternative-clock-label">{clock.label}</span>
+              </div>
+            ))}
+          </div>
+        )}
+
         {/* Date display */}
         <div className="date-display">{mainDateDisplay}</div>
 
+        {/* Gregorian notes */}
+        {gregorianInfo && <div className="gregorian-info">{gregorianInfo}</div>}
+
+        {/* Countdown to the next Islamic prayer */}
+        {nextPrayerDisplay && (
+          <div className="next-prayer" title={prayerTimes?.method}>
+            {nextPrayerDisplay}
+          </div>
+        )}
+
         {/* French Revolutionary calendar info if available */}
         {frenchRevolutionaryInfo && (
           <div className="revolutionary-calendar">
             <div className="revolutionary-date">
               {frenchRevolutionaryInfo.date}
             </div>
+            {userSettings?.show_decimal_time && (
+              <div className="revolutionary-time">
+                {formatDecimalTime(currentTime, userSettings.show_seconds ?? false)}
+              </div>
+            )}
             {frenchRevolutionaryInfo.additional_info && (
               <div className="revolutionary-info">
                 {frenchRevolutionaryInfo.additional_info}